@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::{input::INPUT_DIR, read_lines, ParseError, Part};
+
+/// The known answers of all days, as stored in "inputs/answers".
+///
+/// Each line holds a single answer, keyed by the input-file (without directory) and the part, eg:
+///
+/// ```text
+/// # day1 example
+/// day1.test part1: 24000
+/// day1.test part2: 45000
+/// # day1 puzzle
+/// day1 part1: 74711
+/// ```
+///
+/// Answers are compared on their displayed value. Answers spanning multiple lines or having leading or trailing
+/// whitespace are written quoted, using `\n`, `\"` and `\\` as escapes.
+/// Empty lines and lines starting with '#' are ignored.
+pub struct KnownAnswers(HashMap<Key, String>);
+
+/// Day, input extension (None for the puzzle input) and part.
+type Key = (u32, Option<String>, Part);
+
+/// Outcome of checking an answer against the known answers.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+pub fn get_answers_file() -> PathBuf {
+    get_answers_file_in(Path::new(INPUT_DIR))
+}
+
+pub fn get_answers_file_in(dir: &Path) -> PathBuf {
+    dir.join("answers")
+}
+
+impl KnownAnswers {
+    pub fn load() -> Result<Self, ParseError> {
+        KnownAnswers::load_from(&get_answers_file())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, ParseError> {
+        let answers = read_lines(path)?
+            .parse_lines(|line| {
+                let line = line.trim_end();
+                if line.is_empty() || line.starts_with('#') {
+                    return Ok(None);
+                }
+                parse_answer(line).map(Some)
+            })
+            .map_err(|err| err.in_file(path))?;
+
+        Ok(KnownAnswers(answers.into_iter().flatten().collect()))
+    }
+
+    pub fn get(&self, day: u32, ext: Option<&str>, part: Part) -> Option<&str> {
+        self.0
+            .get(&(day, ext.map(str::to_owned), part))
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, ext: Option<&str>, part: Part, answer: &str) -> Check {
+        match self.get(day, ext, part) {
+            None                           => Check::Missing,
+            Some(known) if known == answer => Check::Pass,
+            Some(known)                    => Check::Fail { expected: known.to_owned() },
+        }
+    }
+}
+
+fn parse_answer(line: &str) -> Result<(Key, String), ParseError> {
+    let expected = "expected '<input> <part>: <answer>'";
+    let (key, value) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::new(expected))?;
+    let (input, part) = key
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(expected))?;
+
+    let (day, ext) = match input.split_once('.') {
+        Some((day, ext)) => (day, Some(ext.to_owned())),
+        None             => (input, None),
+    };
+    let day = day
+        .strip_prefix("day")
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| ParseError::new(format!("invalid input {:?}", input)).at_column(1))?;
+    let part = match part.trim() {
+        "part1" => Part::One,
+        "part2" => Part::Two,
+        _       => return Err(ParseError::new(format!("invalid part {:?}", part)).within(line, part)),
+    };
+
+    let value = unquote(value).map_err(|err| err.within(line, value))?;
+    Ok(((day, ext, part), value))
+}
+
+fn unquote(value: &str) -> Result<String, ParseError> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        return Ok(value.trim().to_owned());
+    };
+
+    let mut unquoted = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"'  if chars.as_str().trim().is_empty() => return Ok(unquoted),
+            '"'  => return Err(ParseError::new("unexpected text after closing quote")),
+            '\\' => match chars.next() {
+                Some('n')  => unquoted.push('\n'),
+                Some('"')  => unquoted.push('"'),
+                Some('\\') => unquoted.push('\\'),
+                _          => return Err(ParseError::new("invalid escape")),
+            },
+            c    => unquoted.push(c),
+        }
+    }
+    Err(ParseError::new("missing closing quote"))
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Pass              => write!(f, "pass"),
+            Check::Fail { expected } => write!(f, "FAIL (expected {:?})", expected),
+            Check::Missing           => write!(f, "missing"),
+        }
+    }
+}
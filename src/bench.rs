@@ -0,0 +1,96 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::{Context, Day, Input, ParseError, Part};
+
+/// Summary of the wall-time of a single stage (parsing or a part) over all runs.
+#[derive(Clone,Copy,Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// The timings of benchmarking a day.
+pub struct Bench {
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Runs the day on the given input `runs` times, after a warm-up of a tenth of the runs.
+/// The input is copied before each run, but only parsing and solving the parts are timed.
+pub fn bench(day: &Day, input: &Input, ctx: &Context, only: Option<Part>, runs: usize) -> Result<Bench, ParseError> {
+    assert!(runs > 0);
+    let warmup = runs.div_ceil(10);
+
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts: Vec<(Part, Vec<Duration>)> = Vec::new();
+    for run in 0..warmup + runs {
+        let answers = (day.solve)(input.clone(), ctx, only)?;
+        if run < warmup {
+            continue;
+        }
+
+        parse.push(answers.parse);
+        for (i, answer) in answers.parts.iter().enumerate() {
+            if parts.len() <= i {
+                parts.push((answer.part, Vec::with_capacity(runs)));
+            }
+            parts[i].1.push(answer.elapsed);
+        }
+    }
+
+    Ok(Bench {
+        day: day.day,
+        runs,
+        parse: Stats::from_samples(parse),
+        parts: parts.into_iter().map(|(part, samples)| (part, Stats::from_samples(samples))).collect(),
+    })
+}
+
+impl Bench {
+    /// Formats the timings as a single line of JSON, with all durations in nanoseconds.
+    pub fn to_json(&self) -> String {
+        let stats = |stats: &Stats| format!(
+            r#"{{"min_ns":{},"median_ns":{},"max_ns":{}}}"#,
+            stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos()
+        );
+
+        let mut json = format!(r#"{{"day":{},"runs":{},"parse":{}"#, self.day, self.runs, stats(&self.parse));
+        for (part, part_stats) in &self.parts {
+            json += &format!(r#","{}":{}"#, part, stats(part_stats));
+        }
+        json += "}";
+        json
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "min {:?}, median {:?}, max {:?}", self.min, self.median, self.max)
+    }
+}
+
+impl Display for Bench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "day{} ({} runs)", self.day, self.runs)?;
+        write!(f, "  parse: {}", self.parse)?;
+        for (part, stats) in &self.parts {
+            write!(f, "\n  {}: {}", part, stats)?;
+        }
+        Ok(())
+    }
+}
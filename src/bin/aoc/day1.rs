@@ -0,0 +1,48 @@
+use aoc2022::{Context, Input, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Parsed = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Vec<i32>, ParseError> {
+        calorie_sums(input)
+    }
+
+    fn part1(sums: &Vec<i32>, _ctx: &Context) -> i32 {
+        *sums.iter().max().unwrap()
+    }
+
+    fn part2(sums: &Vec<i32>, _ctx: &Context) -> i32 {
+        let mut sums = sums.clone();
+        sums.sort();
+        sums.iter().rev().take(3).sum()
+    }
+}
+
+fn calorie_sums(mut input: Input) -> Result<Vec<i32>, ParseError> {
+    // empty lines separate the elves
+    input
+        .groups()
+        .map(|elf| Ok(elf.parse_lines(|line| Ok(line.parse::<i32>()?))?.iter().sum()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day1>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day1>();
+    }
+}
\ No newline at end of file
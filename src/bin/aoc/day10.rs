@@ -1,21 +1,22 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, fmt::Display};
 
-use aoc2022::{run_and_print, Input};
+use aoc2022::{Context, Input, ParseError, Solution};
 
 #[derive(Clone,Copy,Debug)]
-enum Op {
+pub enum Op {
     Noop,
     Addx(isize),
 }
 
 impl Op {
-    fn parse(s: String) -> Self {
-        if &s == "noop" {
-            Op::Noop
-        } else if let Some(num) = s.strip_prefix("addx ").and_then(|s| s.parse::<isize>().ok()) {
-            Op::Addx(num)
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        if s == "noop" {
+            Ok(Op::Noop)
+        } else if let Some(num) = s.strip_prefix("addx ") {
+            let num = num.parse::<isize>().map_err(|err| ParseError::from(err).within(s, num))?;
+            Ok(Op::Addx(num))
         } else { 
-            panic!("invalid op: {}", s)
+            Err(ParseError::new(format!("invalid op: {}", s)))
         }
     }
 
@@ -71,10 +72,15 @@ impl Crt {
         }
     }
 
-    fn display(&self) {
-        self.pixels
-            .chunks(40)
-            .for_each(|cs| println!("{}", String::from_iter(cs.iter())));
+}
+
+impl Display for Crt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row, cs) in self.pixels.chunks(40).enumerate() {
+            if row != 0 { writeln!(f)?; }
+            write!(f, "{}", String::from_iter(cs.iter()))?;
+        }
+        Ok(())
     }
 }
 
@@ -86,27 +92,42 @@ fn tick_system_return_system_strength(cycle: usize, cpu: &mut Cpu, crt: &mut Crt
     x * (cycle as isize + 1)
 }
 
-fn run(input: Input) -> isize {
-    let mut cpu = Cpu::new(input.map(Op::parse));
+fn run_system(ops: &[Op]) -> (isize, Crt) {
+    let mut cpu = Cpu::new(ops.iter().copied());
     let mut crt = Crt::new();
 
     // Knowing the size of the screen, emit a cycle for each pixel,
     // ticking the whole system and collecing the signal-strenght values for
     // the requested cycles.
-    let part1 = (0..40*6)
-        .into_iter()
+    let strength = (0..40*6)
         .map(|cycle| tick_system_return_system_strength(cycle, &mut cpu, &mut crt))
         .skip(19)
         .step_by(40)
         .sum();
 
-    crt.display();
-
-    part1
+    (strength, crt)
 }
 
-fn main() {
-    run_and_print(run);
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Parsed = Vec<Op>;
+    type Part1 = isize;
+    type Part2 = String;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Vec<Op>, ParseError> {
+        input.parse_lines(Op::parse)
+    }
+
+    fn part1(ops: &Vec<Op>, _ctx: &Context) -> isize {
+        run_system(ops).0
+    }
+
+    fn part2(ops: &Vec<Op>, _ctx: &Context) -> String {
+        run_system(ops).1.to_string()
+    }
 }
 
 #[cfg(test)]
@@ -115,11 +136,11 @@ mod test {
 
     #[test]
     fn example() {
-        test_example(crate::run, 13140)
+        test_example::<super::Day10>();
     }
 
     #[test]
     fn puzzle() {
-        test_puzzle(crate::run, 12840)
+        test_puzzle::<super::Day10>();
     }
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc2022::{Context, Group, Input, ParseError, Solution};
+
+#[derive(Clone)]
+struct Inspection {
+    oper: char,
+    number: Option<usize>,
+    divisor: usize,
+    on_true: usize,
+    on_false: usize,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    inspection: Inspection,
+}
+
+impl Monkey {
+    /// Parses the description of a single monkey, given as a group of 6 lines.
+    fn parse(group: &Group) -> Result<Monkey, ParseError> {
+        let lines = group.expect_lines::<6>()?;
+        let items    = field(group, 1, 18)?
+            .split(", ")
+            .map(|n| n.parse().map_err(|err| ParseError::from(err).within(lines[1], n)))
+            .collect::<Result<_, _>>()
+            .map_err(|err: ParseError| group.at_line(1, err))?;
+        let oper     = match field(group, 2, 23)?.chars().next() {
+            Some(oper @ ('+' | '*')) => oper,
+            _ => return Err(group.at_line(2, ParseError::new("expected '+' or '*'").at_column(24))),
+        };
+        let number   = field(group, 2, 25)?.parse().ok();
+        let divisor  = parse_field(group, 3, 21)?;
+        let on_true  = parse_field(group, 4, 29)?;
+        let on_false = parse_field(group, 5, 30)?;
+        
+        Ok(Monkey { items, inspection: Inspection { oper, number, divisor, on_true, on_false }})
+    }
+
+    fn inspect_items(&mut self, factor: usize, lcm: Option<usize>) -> Vec<(usize, usize)> {
+        self.items.drain(..).map(|item| self.inspection.inspect_item(item, factor, lcm)).collect()
+    }
+}
+
+/// Returns the contents of the given line of a monkey's description, starting at the given offset.
+fn field<'a>(group: &Group<'a>, idx: usize, offset: usize) -> Result<&'a str, ParseError> {
+    let line = group.lines()[idx];
+    line
+        .get(offset..)
+        .ok_or_else(|| group.at_line(idx, ParseError::new("line too short").at_column(line.len() + 1)))
+}
+
+fn parse_field<T>(group: &Group, idx: usize, offset: usize) -> Result<T, ParseError>
+where T: FromStr,
+      <T as FromStr>::Err: Into<ParseError>,
+{
+    field(group, idx, offset)?
+        .parse()
+        .map_err(|err: T::Err| group.at_line(idx, err.into().at_column(offset + 1)))
+}
+
+impl Inspection {
+    fn inspect_item(&self, item: usize, factor: usize, lcm: Option<usize>) -> (usize, usize) {
+        let mut level = match self.oper {
+            '+' => item + self.number.unwrap_or(item),
+            '*' => item * self.number.unwrap_or(item),
+            _   => panic!("invalid oper '{}'", self.oper),
+        };
+        level /= factor;
+        if let Some(lcm) = lcm {
+            level %= lcm;
+        }
+        let divisible = level % self.divisor == 0;
+        (level, if divisible { self.on_true } else { self.on_false })
+    }
+}
+
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for item in &self.items {
+            write!(f, "{}, ", item)?;
+        }
+        Ok(())
+    }
+}
+
+fn run_round(monkeys: &mut [Monkey], inspected: &mut [usize], factor: usize, lcm: Option<usize>) {
+    for from in 0..monkeys.len() {
+        for (level, to) in monkeys[from].inspect_items(factor, lcm) {
+            monkeys[to].items.push(level);
+            inspected[from] += 1;
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn debug_monkeys(monkeys: &[Monkey]) {
+    for (n,monkey) in monkeys.iter().enumerate() {
+        println!("monkey {}: {:?}", n, monkey.items);
+    }
+}
+
+fn calc_monkey_business(monkeys: &mut [Monkey], rounds: usize, factor: usize, lcm: Option<usize>) -> usize {
+    let num = monkeys.len();
+    let mut inspected = vec![0;num];
+    for _ in 0..rounds {
+        run_round(monkeys, &mut inspected, factor, lcm);
+        //debug_monkeys(&monkeys);
+    }
+
+    inspected.sort();
+    inspected[num-1] * inspected[num-2]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Parsed = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(mut input: Input, _ctx: &Context) -> Result<Vec<Monkey>, ParseError> {
+        let monkeys = input
+            .groups()
+            .map(|group| Monkey::parse(&group))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (n, monkey) in monkeys.iter().enumerate() {
+            let Inspection { on_true, on_false, .. } = monkey.inspection;
+            if on_true >= monkeys.len() || on_false >= monkeys.len() {
+                return Err(ParseError::new(format!("monkey {} throws to unknown monkey", n)));
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Vec<Monkey>, _ctx: &Context) -> usize {
+        calc_monkey_business(&mut monkeys.clone(), 20, 3, None)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>, _ctx: &Context) -> usize {
+        let lcm = monkeys
+            .iter()
+            .map(|monkey| monkey.inspection.divisor)
+            .fold(1, num::integer::lcm);
+
+        calc_monkey_business(&mut monkeys.clone(), 10000, 1, Some(lcm))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day11>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day11>();
+    }
+}
\ No newline at end of file
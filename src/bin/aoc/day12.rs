@@ -0,0 +1,92 @@
+use aoc2022::{Context, Input, Opt, ParseError, Solution, grid::{Grid, Cell, Paths}, render::{Color, Glyph, Renderer}};
+
+pub struct Map {
+    grid: Grid<char>,
+    start: Cell,
+    end: Cell,
+}
+
+impl Map {
+    fn parse(mut input: Input) -> Result<Self, ParseError> {
+        let first = input.line() + 1;
+        let mut grid = Grid::from_lines_checked(input.lines(), first, |c| c)?;
+
+        let start = grid.find(&'S').ok_or_else(|| ParseError::new("missing start 'S'"))?;
+        let end = grid.find(&'E').ok_or_else(|| ParseError::new("missing end 'E'"))?;
+        grid[start] = 'a';
+        grid[end] = 'z';
+
+        Ok(Self { grid, start, end })
+    }
+    
+    // searching reverse path (from end to start) allows to find the paths from all cells leading to end,
+    // stepping down at most one level in reverse
+    fn paths_to_end(&self) -> Paths<usize> {
+        self.grid.bfs([self.end], |&from, &to| to as u32 + 1 >= from as u32)
+    }
+
+    // paths contains reverse paths, so show S at the end of the path and E at its start
+    fn display_path(&self, paths: &Paths<usize>, start: Cell) {
+        let renderer = Renderer::new().color(true).highlight_color(Color::Green);
+        let glyph = |&c: &char| Glyph::colored(c, Color::Gray);
+        let mut grid = self.grid.clone();
+        grid[start] = 'S';
+        grid[self.end] = 'E';
+        println!("{}", paths.render_path(&grid, start, renderer, glyph).unwrap());
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const OPTIONS: &'static [Opt] = &[
+        Opt::flag("debug", "print the shortest paths"),
+    ];
+
+    type Parsed = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Map, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part1(map: &Map, ctx: &Context) -> usize {
+        let paths = map.paths_to_end();
+        if ctx.flag("debug") {
+            map.display_path(&paths, map.start);
+        }
+
+        paths.distance(map.start).unwrap()
+    }
+
+    fn part2(map: &Map, ctx: &Context) -> usize {
+        let paths = map.paths_to_end();
+        let (start,steps) = paths
+            .reached()
+            .filter(|(cell,_)| map.grid[*cell] == 'a')
+            .min_by_key(|(_,count)| *count)
+            .unwrap();
+        if ctx.flag("debug") {
+            map.display_path(&paths, start);
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day12>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day12>();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,150 @@
+use std::{iter::Peekable, fmt::Display};
+
+use aoc2022::{Context, Input, ParseError, Solution};
+use peeking_take_while::PeekableExt;
+
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub enum Data {
+    Number(usize),
+    List(Vec<Data>),
+}
+
+impl Data {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut input = input.char_indices().peekable();
+        let data = Data::parse_iter(&mut input)?;
+        match input.next() {
+            None        => Ok(data),
+            Some((i,c)) => Err(ParseError::new(format!("unexpected {:?}", c)).at_column(i + 1)),
+        }
+    }
+
+    fn parse_iter(input: &mut Peekable<impl Iterator<Item=(usize,char)>>) -> Result<Self, ParseError> {
+        if input.next_if(|(_,c)| *c == '[').is_some() {
+            let mut items = Vec::new();
+            loop {
+                if input.next_if(|(_,c)| *c == ']').is_some() {
+                    break;
+                }
+                items.push(Data::parse_iter(input)?);
+                input.next_if(|(_,c)| *c == ',');
+            }
+            Ok(Data::List(items))
+        } else {
+            let pos = input.peek().map(|(i,_)| *i);
+            let num = input
+                .peeking_take_while(|(_,c)| c.is_ascii_digit())
+                .map(|(_,c)| c)
+                .collect::<String>()
+                .parse()
+                .map_err(|_| {
+                    let err = ParseError::new("expected number or list");
+                    match pos {
+                        Some(i) => err.at_column(i + 1),
+                        None    => err,
+                    }
+                })?;
+            Ok(Data::Number(num))
+        }
+    }
+}
+
+impl Ord for Data {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use Data::*;
+        match (self, other) {
+            (Number(x), Number(y)) => x.cmp(y),
+            (List(xs), List(ys))   => xs.cmp(ys), // rust lexicographical ordering is just fine!
+            (Number(x), List(_))   => List(vec![Number(*x)]).cmp(other),
+            (List(_), Number(y))   => self.cmp(&List(vec![Number(*y)])),
+        }
+    }
+}
+
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::Number(x) => write!(f, "{}", x)?,
+            Data::List(xs) => {
+                write!(f, "[")?;
+                for (i,x) in xs.iter().enumerate() {
+                    if i != 0 { write!(f, ",")?; }
+                    write!(f, "{}", x)?;
+                }
+                write!(f, "]")?;
+            },
+        }
+        Ok(())
+    }
+}
+
+fn separator(num: usize) -> Data {
+    Data::List(vec![Data::List(vec![Data::Number(num)])])
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Parsed = Vec<(Data,Data)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(mut input: Input, _ctx: &Context) -> Result<Self::Parsed, ParseError> {
+        input
+            .groups()
+            .map(|pair| {
+                let [left, right] = pair.expect_lines()?;
+                let packet = |i, line| Data::parse(line).map_err(|err| pair.at_line(i, err));
+                Ok((packet(0, left)?, packet(1, right)?))
+            })
+            .collect()
+    }
+
+    fn part1(packets: &Self::Parsed, _ctx: &Context) -> usize {
+        packets
+            .iter()
+            .enumerate()
+            .filter(|(_,(x,y))| x <= y)
+            .map(|(i,_)| i + 1)
+            .sum()
+    }
+
+    fn part2(packets: &Self::Parsed, _ctx: &Context) -> usize {
+        let sep1 = separator(2);
+        let sep2 = separator(6);
+
+        let mut index   = 1; // index of first separator, starts at 1
+        let mut between = 1; // number of items between first and second separator (including 1 for first separator)
+        for (a,b) in packets {
+            if *a < sep1      { index   += 1 }
+            else if *a < sep2 { between += 1 }
+            if *b < sep1      { index   += 1 }
+            else if *b < sep2 { between += 1 }
+        }
+
+        index*(index+between)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day13>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day13>();
+    }
+}
\ No newline at end of file
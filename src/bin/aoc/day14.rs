@@ -0,0 +1,226 @@
+use std::collections::HashSet;
+
+use aoc2022::{Context, Input, Opt, ParseError, Solution, parse_pair, grid::{Cell, LineIter, Slope, SparseGrid}, render::{Color, Glyph, Renderer}};
+
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+    Source,
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    tiles: SparseGrid<Tile>,
+    source: Cell,
+    bottom_wall: usize,
+    last_trail: Vec<Cell>,
+}
+
+impl Cave {
+    pub fn from_input(input: Input) -> Result<Self, ParseError> {
+        let mut tiles = SparseGrid::new(Tile::Air);
+        let obstacles = input.parse_lines(parse_line)?;
+        for obstacle in &obstacles {
+            for corners in obstacle[..].windows(2) {
+                for cell in LineIter::new(corners[0], corners[1]).expect("checked by parse_line") {
+                    tiles.set(cell.row as isize, cell.col as isize, Tile::Rock);
+                }
+            }
+        }
+
+        let last_trail = Vec::new();
+        let bottom_wall = tiles
+            .bounds()
+            .map(|bounds| bounds.max_row as usize)
+            .ok_or_else(|| ParseError::new("missing rock paths"))?;
+        let source = Cell { row: 0, col: 500 };
+        tiles.set(source.row as isize, source.col as isize, Tile::Source);
+
+        Ok(Cave { tiles, source, bottom_wall, last_trail })
+    }
+
+    /// Drops a unit of sand and returns if it has landed.
+    /// This can be false in case there is no floor and the 
+    fn drop_sand_unit(&mut self, has_floor: bool) -> bool {
+        // Start from where we last were (this saves a lot of double work!)
+        // or the source in case we don't have any trail yet.
+        let mut sand = self.last_trail.pop().unwrap_or(self.source);
+
+        loop {
+            if has_floor {
+                // Check for implicit floor if present,
+                if sand.row == self.bottom_wall + 1 {
+                    self.tiles.set(sand.row as isize, sand.col as isize, Tile::Sand);
+                    return true;
+                }
+            } else {
+                // else check for falling below lowest obstacle.
+                if sand.row >= self.bottom_wall + 2 {
+                    return false
+                }
+            }
+
+            // Try falling down or else returning we have landed
+            if let Some(next) = self.fall_next(sand) {
+                self.last_trail.push(sand);
+                sand = next;
+            } else {
+                self.tiles.set(sand.row as isize, sand.col as isize, Tile::Sand);
+                return true;
+            }
+        }
+    }
+
+    #[inline]
+    fn available(&self, cell: &Cell) -> bool {
+        *self.tiles.get(cell.row as isize, cell.col as isize) == Tile::Air
+    }
+
+    fn fall_next(&self, mut sand: Cell) -> Option<Cell> {
+        sand.row += 1;
+        if self.available(&sand) { return Some(sand) }
+
+        sand.col -= 1;
+        if self.available(&sand) { return Some(sand) }
+
+        sand.col += 2;
+        if self.available(&sand) { return Some(sand) }
+
+        None
+    }
+
+    fn is_blocked(&self) -> bool {
+        *self.tiles.get(self.source.row as isize, self.source.col as isize) == Tile::Sand
+    }
+}
+
+impl Tile {
+    fn glyph(&self) -> Glyph {
+        match self {
+            Tile::Air    => ' '.into(),
+            Tile::Rock   => Glyph::colored('█', Color::Gray),
+            Tile::Sand   => Glyph::colored('░', Color::Yellow),
+            Tile::Source => '+'.into(),
+        }
+    }
+}
+
+impl Cave {
+    /// Renders the part of the cave around the current trail of sand, with the trail marked.
+    fn render(&self) -> String {
+        let Cell { row, col } = self.last_trail.last().copied().unwrap_or(self.source);
+        let trail: HashSet<_> = self.last_trail.iter().map(|cell| (cell.row as isize, cell.col as isize)).collect();
+        Renderer::new()
+            .color(true)
+            .around(row as isize, col as isize, VIEWPORT.0, VIEWPORT.1)
+            .cells(self.tiles.bounds().unwrap(), |row, col| match self.tiles.get(row, col) {
+                Tile::Air if trail.contains(&(row, col)) => Glyph::colored('~', Color::Red),
+                tile                                     => tile.glyph(),
+            })
+    }
+}
+
+/// The number of rows and columns shown while visualizing.
+const VIEWPORT: (usize, usize) = (40, 120);
+
+fn parse_line(line: &str) -> Result<Vec<Cell>, ParseError> {
+    let mut corners: Vec<Cell> = Vec::new();
+    for s in line.split(" -> ") {
+        let (x,y) = parse_pair(s, ",").map_err(|err| err.within(line, s))?;
+        let corner = Cell{row: y, col: x};
+        if let Some(&prev) = corners.last() {
+            LineIter::strict(prev, corner, Slope::Straight)
+                .map_err(|err| ParseError::new(format!("invalid rock path: {}", err)).within(line, s))?;
+        }
+        corners.push(corner);
+    }
+    Ok(corners)
+}
+
+fn get_input() -> usize {
+    let mut line = String::new();
+    _ = std::io::stdin().read_line(&mut line).unwrap();
+    line.trim().parse::<usize>().unwrap_or(0)
+}
+
+fn get_visualizer(debug: bool) -> impl FnMut(usize, &Cave) {
+    let mut skip = 0;
+    move |count, cave| {
+        if debug {
+            if skip == 0 {
+                print!("\x1B[2J\x1B[H");
+                println!("After {} sand units:", count);
+                print!("{}", cave.render());
+                skip = get_input();
+            } else {
+                skip -= 1;
+            }
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const OPTIONS: &'static [Opt] = &[
+        Opt::flag("debug", "visualize the falling sand; press enter to step or type a number of steps to skip"),
+    ];
+
+    type Parsed = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Cave, ParseError> {
+        Cave::from_input(input)
+    }
+
+    fn part1(cave: &Cave, ctx: &Context) -> usize {
+        let mut cave = cave.clone();
+
+        // some visualization when "--debug" is given
+        let mut visualize = get_visualizer(ctx.flag("debug"));
+        visualize(0, &cave);
+
+        // drop until it falls below lowest obstacle
+        let mut count = 0;
+        while cave.drop_sand_unit(false) {
+            count += 1;
+            visualize(count, &cave);
+        }
+        count
+    }
+
+    fn part2(cave: &Cave, ctx: &Context) -> usize {
+        let mut cave = cave.clone();
+
+        let mut visualize = get_visualizer(ctx.flag("debug"));
+        visualize(0, &cave);
+
+        // drop until source is blocked
+        let mut count = 0;
+        while !cave.is_blocked() {
+            cave.drop_sand_unit(true);
+            count += 1;
+            visualize(count, &cave);
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day14>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day14>();
+    }
+}
\ No newline at end of file
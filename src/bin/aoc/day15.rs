@@ -0,0 +1,336 @@
+use aoc2022::point::Offset;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use aoc2022::{Context, Input, Opt, ParseError, Solution, grid::Bounds, point::Point, render::Renderer};
+
+// Wrapper with same functionality as RangeInclusive<isize>
+// Not using this one because I want to define an absolute ordering so merging can be done more efficiently.
+#[derive(Copy,Clone,Debug,Eq,Ord,PartialEq,PartialOrd)]
+struct Coverage(isize, isize);
+
+impl Coverage {
+    fn len(&self) -> usize {
+        (self.1 - self.0 + 1) as usize
+    }
+
+    fn try_merge(&mut self, other: &Self) -> bool {
+        if other.1 < self.0 - 1 || other.0 > self.1 + 1 {
+            false
+        } else {
+            self.0 = min(self.0, other.0);
+            self.1 = max(self.1, other.1);
+            true
+        }    
+    }
+}
+
+#[derive(Debug)]
+struct CombinedCoverage(Vec<Coverage>);
+
+impl FromIterator<Coverage> for CombinedCoverage {
+    fn from_iter<T: IntoIterator<Item = Coverage>>(iter: T) -> Self {
+        // Fetch all and then sort (less expensive than inserting sorted).
+        let mut coverages = Vec::from_iter(iter);
+        coverages.sort();
+
+        // When sorted on start-point, we can try merging with previous range or add as new otherwise.
+        CombinedCoverage(coverages
+            .iter()
+            .fold(Vec::<Coverage>::new(), |mut acc,c| {
+                if !acc.last_mut().map(|last| last.try_merge(c)).unwrap_or(false) {
+                    acc.push(*c);
+                }
+                acc
+            })
+        )
+    }
+}
+
+pub struct Sensor {
+    sensor: Point<2>,
+    beacon: Point<2>,
+}
+
+impl Sensor {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(-?\d+)").unwrap());
+        let nums: Vec<isize> = RE
+            .find_iter(s)
+            .map(|m| m.as_str().parse().map_err(|err| ParseError::from(err).at_column(m.start() + 1)))
+            .collect::<Result<_,_>>()?;
+        if nums.len() != 4 {
+            return Err(ParseError::new(format!("expected 4 coordinates, found {}", nums.len())));
+        }
+
+        Ok(Sensor {
+            sensor: Point::new(&nums[0..2]),
+            beacon: Point::new(&nums[2..4]),
+        })
+    }
+
+    fn range(&self) -> usize {
+        self.sensor.manhattan_dist(&self.beacon)
+    }
+
+    fn covers(&self, other: &Point<2>) -> bool {
+        self.sensor.manhattan_dist(other) <= self.range()
+    }
+
+    fn row_coverage(&self, row: isize) -> Option<Coverage> {
+        let x = self.sensor.0[0];
+        let proj = Point::<2>([x, row]);
+        let dist = self.sensor.manhattan_dist(&proj);
+        let size = self.range() as isize - dist as isize;
+
+        if size >= 0 {
+            Some(Coverage(x-size, x+size))
+        } else {
+            None
+        }
+    }
+
+    fn diags(&self) -> Diags {
+        let offset = Offset::<2>([0, self.range() as isize]);
+        let (h1,v1) = xy_to_uv(self.sensor - offset);
+        let (h2,v2) = xy_to_uv(self.sensor + offset);
+        Diags { us: [h1, h2], vs: [v1, v2] }
+    }
+}
+
+#[derive(Debug,Eq,PartialEq)]
+struct Diags {
+    us: [isize;2],
+    vs: [isize;2],
+}
+
+fn xy_to_uv(p: Point<2>) -> (isize, isize) {
+    (p.0[0] + p.0[1], p.0[0] - p.0[1])
+}
+
+fn uv_to_xy((u, v): (isize, isize)) -> Point<2> {
+    Point::<2>([(u + v) / 2, (u - v) / 2])
+}
+
+#[derive(Debug)]
+struct DiagonalSet(HashSet<isize>);
+
+impl DiagonalSet {
+    // Iterating over all pairs of diagonals and finding the ones which have a gap of one between.
+    fn single_gaps(&self) -> impl Iterator<Item=isize> + '_ {
+        let diags = self.0.iter();
+        diags
+            .clone()
+            .flat_map(move |d1| diags.clone().map(|d2| (*d1, *d2)))
+            .filter(|(d1,d2)| d2 - d1 == 2)
+            .map(|(d1,_)| d1 + 1)
+    }
+}
+
+// Fancy dumping!
+fn dump(sensors: &Vec<Sensor>, row: Option<isize>, full: bool) {
+    let bounds = if full {
+        // the sensors and beacons, extended with the range of each sensor
+        sensors
+            .iter()
+            .flat_map(|s| {
+                let range = s.range() as isize;
+                [s.beacon, s.sensor + Offset([-range, -range]), s.sensor + Offset([range, range])]
+            })
+            .fold(None, |bounds: Option<Bounds>, p| Some(match bounds {
+                Some(bounds) => bounds.extend(p.0[1], p.0[0]),
+                None         => Bounds::of(p.0[1], p.0[0]),
+            }))
+            .unwrap()
+    } else {
+        let center = row.unwrap();
+        Bounds { min_row: 0, max_row: center * 2, min_col: 0, max_col: center * 2 }
+    };
+
+    let mut renderer = Renderer::new().labels(true).color(true);
+    if let Some(row) = row {
+        renderer = renderer.highlight_row(row);
+    }
+    print!("{}", renderer.cells(bounds, |y, x| {
+        let p = Point::<2>([x,y]);
+        let mut c = ' ';
+        for s in sensors {
+            if s.sensor == p {
+                return 'S'.into();
+            } else if s.beacon == p {
+                return 'B'.into();
+            } else if s.sensor.manhattan_dist(&p) <= s.range() {
+                c = '#';
+            }
+        }
+        c.into()
+    }));
+}
+
+// Collect all pairs of diagonals into two lists of both directions.
+// Using hash-set for deduplication.
+fn collect_diags(iter: impl Iterator<Item=Diags>) -> (DiagonalSet, DiagonalSet) {
+    let mut us = HashSet::new();
+    let mut vs = HashSet::new();
+
+    for diag in iter {
+        us.extend(diag.us);
+        vs.extend(diag.vs);
+    }
+
+    (DiagonalSet(us), DiagonalSet(vs))
+}
+
+pub struct Day15;
+
+/// The sensors together with the row to inspect for part 1 and the search bounds for part 2.
+pub struct Scan {
+    row: isize,
+    bounds: RangeInclusive<isize>,
+    sensors: Vec<Sensor>,
+}
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const OPTIONS: &'static [Opt] = &[
+        Opt::int("row", "use this row for part 1 instead of the one from the input (and highlight it)"),
+        Opt::flag("debug", "print the grid of sensors and beacons"),
+        Opt::flag("full", "print the whole range of all sensors (with --debug)"),
+    ];
+
+    type Parsed = Scan;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(mut input: Input, _ctx: &Context) -> Result<Scan, ParseError> {
+        // NOTE: in order to have different target rows for example vs puzzle without modifying the code,
+        //       I have added the target-row to the input.
+        //       Now we can parse the input-file and both runs and tests will pick the correct value.
+        let line = input.next_line().ok_or_else(|| ParseError::new("missing target row"))?;
+        let row: isize = line.parse().map_err(|err| ParseError::from(err).at_line(1, line))?;
+        let bounds = 0..=row*2;
+
+        // Parse all sensors.
+        let sensors = input.parse_lines(Sensor::parse)?;
+
+        Ok(Scan { row, bounds, sensors })
+    }
+
+    fn part1(Scan { row, sensors, .. }: &Scan, ctx: &Context) -> usize {
+        // Overrides from options for testing
+        // - with "--debug", the grid will be printed.
+        // - with "--full", the whole range of all sensors will be printed.
+        // - with "--row N", that row will be used for calculations (and will be highlighted)
+        let row = ctx.int("row").map(|row| row as isize).unwrap_or(*row);
+        if ctx.flag("debug") {
+            dump(sensors, Some(row), ctx.flag("full"));
+        }
+
+        // Collect the coverage of each sensor of the requested row (if any)
+        // and combine them all so that no cell is double-counted.
+        let coverages: CombinedCoverage = sensors
+            .iter()
+            .filter_map(|sensor| sensor.row_coverage(row))
+            .collect();
+        let num_coverage: usize = coverages.0
+            .iter()
+            .map(Coverage::len)
+            .sum();
+
+        // find all beacons in the requested row, as these have to be subtracted
+        let num_beacons: usize = sensors
+            .iter()
+            .map(|s| s.beacon)
+            .filter(|beacon| beacon.0[1] == row)
+            .collect::<HashSet<_>>()
+            .len();
+
+        num_coverage - num_beacons
+    }
+
+    fn part2(Scan { bounds, sensors, .. }: &Scan, _ctx: &Context) -> usize {
+        // Each sensor has a range in the shape of a rhombus.
+        // If any gap exists it will lie between the diagonals of the rhombi.
+        // We convert xy-space to uv-space (which is 45° rotated) in which the diagonals are
+        // straight lines and can be expressed by a number.
+        // Collecting all these numbers we only have look between pairs of diagonals that are
+        // exactly 2 units apart, as there is only one hole and as such should be stradled by
+        // diamonds in both directions.
+        let (us,vs) = collect_diags(sensors.iter().map(|s| s.diags()));
+        let us = us.single_gaps().collect::<HashSet<_>>();
+        let vs = vs.single_gaps().collect::<HashSet<_>>();
+
+        // Pairing up the gaps in both directions, converting back to xy and finding the one that is:
+        // - out of reach of all sensors
+        // - withing the target range
+        // should result in a single point.
+        let p = us
+            .iter()
+            .flat_map(|u| vs.iter().map(|v| (*u,*v)))
+            .map(uv_to_xy)
+            .filter(|point| bounds.contains(&point.0[0]) && bounds.contains(&point.0[1]))
+            .find(|point| !sensors.iter().any(|s| s.covers(point)))
+            .unwrap();
+
+        (p.0[0] * 4000000 + p.0[1]) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::{test::{test_example, test_puzzle}, point::Point};
+
+    use super::{Coverage, xy_to_uv, uv_to_xy, Sensor, Diags};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day15>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day15>();
+    }
+
+    #[test]
+    fn try_merge() {
+        let mut a = Coverage(1, 5);
+        let b = Coverage(6, 8);
+        let ab = Coverage(1, 8);
+        let c = Coverage(-5, 2);
+        let abc = Coverage(-5, 8);
+        let x = Coverage(10, 10);
+
+        assert!(a.try_merge(&b));
+        assert_eq!(a, ab);
+        assert!(a.try_merge(&c));
+        assert_eq!(a, abc);
+        assert!(!a.try_merge(&x));
+    }
+
+    #[test]
+    fn test_xy_to_hv() {
+        assert_eq!((0, 0), xy_to_uv(Point::<2>([0, 0])));
+        assert_eq!((1, 1), xy_to_uv(Point::<2>([1, 0])));
+        assert_eq!((1, -1), xy_to_uv(Point::<2>([0, 1])));
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                let p = Point::<2>([x, y]);
+                let q = uv_to_xy(xy_to_uv(p));
+                assert_eq!(p, q);
+            }
+        }
+    }
+
+    #[test]
+    fn test_diag() {
+        let s = Sensor { sensor: Point::<2>([1, 1]), beacon: Point::<2>([0, 0]) };
+        let diags = s.diags();
+
+        assert_eq!(diags, Diags {us: [0, 4], vs: [2, -2]});
+    }
+}
\ No newline at end of file
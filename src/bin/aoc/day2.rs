@@ -1,45 +1,45 @@
-use std::{str::FromStr, cmp::Ordering, fmt::Debug};
+use std::{str::FromStr, cmp::Ordering};
 use num_derive::FromPrimitive;    
 use num_traits::FromPrimitive;
 
-use aoc2022::{parse_pair, run_and_print, Input};
+use aoc2022::{parse_pair, Context, Input, ParseError, Solution};
 
 #[derive(Copy,Clone,Debug,PartialEq,FromPrimitive)]
-enum Item {
+pub enum Item {
     Rock = 0,
     Paper = 1,
     Scissors = 2,
 }
 
 #[derive(Copy,Clone,Debug)]
-enum Action {
+pub enum Action {
     Draw = 0,
     Win = 1,
     Lose = 2,
 }
 
 impl FromStr for Item {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A"|"X" => Ok(Item::Rock),
             "B"|"Y" => Ok(Item::Paper),
             "C"|"Z" => Ok(Item::Scissors),
-            _       => Err(()),
+            _       => Err(ParseError::new(format!("invalid item {:?}", s))),
         }
     }
 }
 
 impl FromStr for Action {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(Action::Lose),
             "Y" => Ok(Action::Draw),
             "Z" => Ok(Action::Win),
-            _       => Err(()),
+            _   => Err(ParseError::new(format!("invalid action {:?}", s))),
         }
     }
 }
@@ -67,26 +67,37 @@ fn action_to_item(a: Item, action: Action) -> Item {
     FromPrimitive::from_u8((a as u8 + action as u8) % 3).unwrap()
 }
 
-fn main() {
-    run_and_print(run);
-}
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    // The second column is both interpreted as item (part 1) and as action (part 2).
+    type Parsed = Vec<(Item, Item, Action)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Self::Parsed, ParseError> {
+        input.parse_lines(|line| {
+            let (a, b) = parse_pair::<Item,Item>(line, " ")?;
+            let (_, action) = parse_pair::<Item,Action>(line, " ")?;
+            Ok((a, b, action))
+        })
+    }
+
+    fn part1(rounds: &Self::Parsed, _ctx: &Context) -> usize {
+        rounds
+            .iter()
+            .map(|(a,b,_)| single_score(*a, *b))
+            .sum()
+    }
 
-fn run(input: Input) -> (usize, usize) {
-    let input: Vec<String> = input.collect();
-
-    let score1: usize = input
-        .iter()
-        .map(|line| parse_pair::<Item,Item>(line, " "))
-        .map(|(a,b)| single_score(a, b))
-        .sum();
-    
-    let score2: usize = input
-        .iter()
-        .map(|line| parse_pair::<Item,Action>(line, " "))
-        .map(|(a,b)| single_score(a, action_to_item(a, b)))
-        .sum();
-
-    (score1, score2)
+    fn part2(rounds: &Self::Parsed, _ctx: &Context) -> usize {
+        rounds
+            .iter()
+            .map(|(a,_,action)| single_score(*a, action_to_item(*a, *action)))
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -95,11 +106,11 @@ mod test {
 
     #[test]
     fn example() {
-        test_example(crate::run, (15, 12));
+        test_example::<super::Day2>();
     }
 
     #[test]
     fn puzzle() {
-        test_puzzle(crate::run, (15422, 15442));
+        test_puzzle::<super::Day2>();
     }
 }
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+use aoc2022::{Context, Input, ParseError, Solution};
+
+#[derive(Clone,Default)]
+pub struct Rucksack(HashSet<char>,HashSet<char>);
+
+impl Rucksack {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        if let Some(pos) = s.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::new("items should be letters").at_column(pos + 1));
+        }
+        if !s.len().is_multiple_of(2) {
+            return Err(ParseError::new("compartments should have equal size"));
+        }
+
+        let l = s.len() / 2;
+        Ok(Rucksack(
+            s[..l].chars().collect(),
+            s[l..].chars().collect()
+        ))
+    }
+
+    fn double_item(&self) -> char {
+        *self.0.intersection(&self.1).next().unwrap()
+    }
+
+    fn take_combined_compartments(&mut self) -> HashSet<char> {
+        self.0.extend(std::mem::take(&mut self.1));
+        std::mem::take(&mut self.0)
+    }
+}
+
+fn priority(item: char) -> u32 {
+    if item as u32 >= 'a' as u32 {
+        item as u32 - 'a' as u32 + 1
+    } else {
+        item as u32 - 'A' as u32 + 27
+    }
+}
+
+fn find_common_item(rucksacks: &mut [Rucksack]) -> char {
+    *rucksacks
+        .iter_mut()
+        .map(Rucksack::take_combined_compartments)
+        .reduce(|acc,item| acc.intersection(&item).copied().collect())
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Parsed = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Vec<Rucksack>, ParseError> {
+        input.parse_lines(Rucksack::parse)
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>, _ctx: &Context) -> u32 {
+        rucksacks
+            .iter()
+            .map(Rucksack::double_item)
+            .map(priority)
+            .sum()
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>, _ctx: &Context) -> u32 {
+        // finding the common item consumes the compartments, so work on a copy
+        rucksacks
+            .clone()
+            .chunks_mut(3)
+            .map(find_common_item)
+            .map(priority)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day3>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day3>();
+    }
+}
\ No newline at end of file
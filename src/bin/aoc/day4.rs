@@ -0,0 +1,67 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use aoc2022::{Context, Input, ParseError, Solution, parse_pair};
+
+pub struct SectionRange(RangeInclusive<usize>);
+
+impl FromStr for SectionRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a,b) = parse_pair(s, "-")?;
+        Ok(SectionRange(a..=b))
+    }
+}
+
+impl SectionRange {
+    fn fully_contains(&self, other: &Self) -> bool {
+        self.0.contains(other.0.start()) && self.0.contains(other.0.end())
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.0.contains(other.0.start()) || self.0.contains(other.0.end())
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Parsed = Vec<(SectionRange,SectionRange)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Self::Parsed, ParseError> {
+        input.parse_lines(|line| parse_pair(line, ","))
+    }
+
+    fn part1(ranges: &Self::Parsed, _ctx: &Context) -> usize {
+        ranges
+            .iter()
+            .filter(|(a,b)| a.fully_contains(b) || b.fully_contains(a))
+            .count()
+    }
+
+    fn part2(ranges: &Self::Parsed, _ctx: &Context) -> usize {
+        ranges
+            .iter()
+            .filter(|(a,b)| a.overlaps(b) || b.overlaps(a))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day4>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day4>();
+    }
+}
\ No newline at end of file
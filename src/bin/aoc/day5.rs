@@ -0,0 +1,123 @@
+use aoc2022::{Context, Input, ParseError, Solution, slice_mut_twice};
+
+#[derive(Clone)]
+pub struct Stacks(Vec<Vec<char>>);
+
+#[derive(Copy,Clone)]
+pub struct Move {
+    num: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Stacks {
+    fn parse(lines: &[&str]) -> Result<Self, ParseError> {
+        // count the numbers below the stacks, the width of the drawing depends on trailing whitespace
+        let num_stacks = lines
+            .last()
+            .map(|line| line.split_whitespace().count())
+            .ok_or_else(|| ParseError::new("missing stacks"))?;
+        let mut stacks = Stacks(vec![vec![];num_stacks]);
+        for line in lines.iter().rev().skip(1) {
+            line
+                .chars()
+                .skip(1)
+                .step_by(4)
+                .zip(stacks.0.iter_mut())
+                .filter(|(c,_)| *c != ' ')
+                .for_each(|(c,stack)| stack.push(c));
+        }
+        Ok(stacks)
+    }
+
+    fn perform_move(&mut self, m: &Move) {
+        let (from, to) = slice_mut_twice(&mut self.0, m.from, m.to);
+        let idx = from.len() - m.num;
+        to.extend(from.drain(idx..).rev());
+    }
+
+    fn perform_multi_move(&mut self, m: &Move) {
+        let (from, to) = slice_mut_twice(&mut self.0, m.from, m.to);
+        let idx = from.len() - m.num;
+        to.extend(from.drain(idx..));
+    }
+
+    fn collect_top_crates(&self) -> String {
+        self.0.iter().map(|stack| *stack.last().unwrap()).collect()
+    }
+}
+
+impl Move {
+    fn parse(s: &str, num_stacks: usize) -> Result<Self, ParseError> {
+        let numbers = s
+            .split(' ')
+            .skip(1)
+            .step_by(2)
+            .map(|n| n.parse::<usize>().map_err(|err| ParseError::from(err).within(s, n)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let [num, from, to] = numbers[..] else {
+            return Err(ParseError::new("expected 'move <num> from <stack> to <stack>'"));
+        };
+        let stack = |n| match n {
+            n if (1..=num_stacks).contains(&n) => Ok(n - 1),
+            n => Err(ParseError::new(format!("no stack {}, there are {} stacks", n, num_stacks))),
+        };
+
+        Ok(Move {
+            num,
+            from: stack(from)?,
+            to:   stack(to)?,
+        })
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Parsed = (Stacks, Vec<Move>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(mut input: Input, _ctx: &Context) -> Result<Self::Parsed, ParseError> {
+        // The drawing of the stacks is followed by the moves, separated by an empty line.
+        let [stacks, moves] = input.sections()?;
+        let stacks = Stacks::parse(stacks.lines())?;
+        let num_stacks = stacks.0.len();
+        let moves = moves.parse_lines(|line| Move::parse(line, num_stacks))?;
+
+        Ok((stacks, moves))
+    }
+
+    fn part1((stacks, moves): &Self::Parsed, _ctx: &Context) -> String {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            stacks.perform_move(m);
+        }
+        stacks.collect_top_crates()
+    }
+
+    fn part2((stacks, moves): &Self::Parsed, _ctx: &Context) -> String {
+        let mut stacks = stacks.clone();
+        for m in moves {
+            stacks.perform_multi_move(m);
+        }
+        stacks.collect_top_crates()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day5>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day5>();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use aoc2022::{Context, Input, ParseError, Solution};
+
+pub struct Day6;
+
+/// The marker positions found on each line of the input.
+pub struct Markers(Vec<usize>);
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Parsed = Vec<String>;
+    type Part1 = Markers;
+    type Part2 = Markers;
+
+    fn parse(mut input: Input, _ctx: &Context) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(lines: &Vec<String>, _ctx: &Context) -> Markers {
+        Markers(lines.iter().map(|line| find_first_different(4, line)).collect())
+    }
+
+    fn part2(lines: &Vec<String>, _ctx: &Context) -> Markers {
+        Markers(lines.iter().map(|line| find_first_different(14, line)).collect())
+    }
+}
+
+impl Display for Markers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, pos) in self.0.iter().enumerate() {
+            if i != 0 { write!(f, ",")?; }
+            write!(f, "{}", pos)?;
+        }
+        Ok(())
+    }
+}
+
+fn find_first_different(num: usize, line: &str) -> usize {
+    let (pos,_) = line
+        .as_bytes()
+        .windows(num)
+        .enumerate()
+        .find(|(_, w)| all_different(w))
+        .unwrap();
+    pos + num
+}
+
+fn all_different<T: PartialEq>(s: &[T]) -> bool {
+    s.iter()
+     .map(|a| s.iter().filter(|b| a == *b).count())
+     .all(|c| c == 1)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day6>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day6>();
+    }
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::{collections::HashMap, str, fmt::Display};
-use aoc2022::{run_and_print, Input, parse_pair};
+use aoc2022::{Context, Input, ParseError, Solution, parse_pair};
 
 #[derive(Default)]
 struct Directory {
@@ -24,8 +24,8 @@ enum Cmd {
 
 impl Cmd {
     #[allow(clippy::manual_map)]
-    fn parse(s: String) -> Option<Self> {
-        if let Some(s) = s.strip_prefix("$ ") {
+    fn parse(s: &str) -> Result<Option<Self>, ParseError> {
+        Ok(if let Some(s) = s.strip_prefix("$ ") {
             if s == "cd .." {
                 Some(Cmd::Parent)
             } else if s == "cd /" {
@@ -38,9 +38,9 @@ impl Cmd {
         } else if let Some(s) = s.strip_prefix("dir ") {
             Some(Cmd::ListDir(s.to_owned()))
         } else {
-            let (size, name) = parse_pair(&s, " ");
+            let (size, name) = parse_pair(s, " ")?;
             Some(Cmd::ListFile(name, size))
-        }
+        })
     }
 }
 
@@ -97,10 +97,11 @@ impl Builder {
     }
 }
 
-fn reconstruct_tree_from_input(input: Input) -> Directory {
-    Builder::new()
-        .run_commands(input.filter_map(Cmd::parse))
-        .into_root()
+fn reconstruct_tree_from_input(input: Input) -> Result<Directory, ParseError> {
+    let cmds = input.parse_lines(Cmd::parse)?;
+    Ok(Builder::new()
+        .run_commands(cmds.into_iter().flatten())
+        .into_root())
 }
 
 impl Directory {
@@ -147,7 +148,7 @@ impl Display for Directory {
 }
 
 #[derive(Default)]
-struct CollectAllSizes {
+pub struct CollectAllSizes {
     sizes: Vec<usize>,
     parents: Vec<usize>,
     current: usize,
@@ -169,30 +170,39 @@ impl DirWalker for CollectAllSizes {
     }
 }
 
-fn run(input: Input) -> (usize, usize) {
-    let root = reconstruct_tree_from_input(input);
+pub struct Day7;
 
-    let mut collector = CollectAllSizes::default();
-    root.walk("/", &mut collector);
-    collector.sizes.sort();
+impl Solution for Day7 {
+    const DAY: u32 = 7;
 
-    let part1 = collector.sizes
-        .iter()
-        .take_while(|v| **v <= 100000)
-        .sum();
+    type Parsed = CollectAllSizes;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let free = 70000000 - collector.current;
-    let required = 30000000;
-    let part2 = *collector.sizes
-        .iter()
-        .find(|size| free + **size >= required)
-        .unwrap();
+    fn parse(input: Input, _ctx: &Context) -> Result<CollectAllSizes, ParseError> {
+        let root = reconstruct_tree_from_input(input)?;
 
-    (part1, part2)
-}
+        let mut collector = CollectAllSizes::default();
+        root.walk("/", &mut collector);
+        collector.sizes.sort();
+        Ok(collector)
+    }
 
-fn main() {
-    run_and_print(run);
+    fn part1(collector: &CollectAllSizes, _ctx: &Context) -> usize {
+        collector.sizes
+            .iter()
+            .take_while(|v| **v <= 100000)
+            .sum()
+    }
+
+    fn part2(collector: &CollectAllSizes, _ctx: &Context) -> usize {
+        let free = 70000000 - collector.current;
+        let required = 30000000;
+        *collector.sizes
+            .iter()
+            .find(|size| free + **size >= required)
+            .unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -201,11 +211,11 @@ mod test {
 
     #[test]
     fn example() {
-        test_example(crate::run, (95437, 24933642))
+        test_example::<super::Day7>();
     }
 
     #[test]
     fn puzzle() {
-        test_puzzle(crate::run, (1723892, 8474158))
+        test_puzzle::<super::Day7>();
     }
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use aoc2022::{Context, Input, ParseError, Solution, grid::{Cell, Dir, Grid, Order}};
+
+#[derive(Clone)]
+pub struct Forest(Grid<u32>);
+
+impl Forest {
+    fn parse(mut input: Input) -> Result<Self, ParseError> {
+        let first = input.line() + 1;
+        Ok(Forest(Grid::parse_digits(input.lines(), first)?))
+    }
+
+    fn scenic_score(&self, cell: Cell) -> usize {
+        let tree = self.0[cell];
+        Dir::orthogonal()
+            .map(|dir| self.0.count_until_blocked(cell, dir, |&other| other >= tree))
+            .product()
+    }
+
+    fn is_visible(&self, cell: Cell) -> bool {
+        let tree = self.0[cell];
+        Dir::orthogonal().any(|dir| self.0.ray(cell, dir).all(|(_, &other)| tree > other))
+    }
+}
+
+fn num_visible_performant(forest: &Forest) -> usize {
+    // The highest tree so far when looking in every direction, a tree is visible if it is higher than the
+    // highest one in front of it.
+    let heights = &forest.0;
+    let highest = |a: &u32, b: &u32| *a.max(b);
+    let sweeps = [
+        (Dir::Right, heights.scan_rows(Order::Forward,  0, highest)),
+        (Dir::Left,  heights.scan_rows(Order::Backward, 0, highest)),
+        (Dir::Down,  heights.scan_cols(Order::Forward,  0, highest)),
+        (Dir::Up,    heights.scan_cols(Order::Backward, 0, highest)),
+    ];
+
+    let visible = heights.map_with_cell(|cell, &tree| sweeps.iter().any(|(dir, sweep)| {
+        heights.step(cell, dir.opposite()).is_none_or(|front| tree > sweep[front])
+    }));
+    visible.data().filter(|&&visible| visible).count()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Parsed = Forest;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Forest, ParseError> {
+        Forest::parse(input)
+    }
+
+    fn part1(forest: &Forest, _ctx: &Context) -> usize {
+        let _ = num_visible_performant(forest);
+
+        forest.0.iter_cells().filter(|&cell| forest.is_visible(cell)).count()
+    }
+
+    fn part2(forest: &Forest, _ctx: &Context) -> usize {
+        forest.0.iter_cells().map(|cell| forest.scenic_score(cell)).max().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::{Input, test::{test_example, test_puzzle}};
+
+    use super::{Forest, num_visible_performant};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day8>();
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day8>();
+    }
+
+    #[test]
+    fn non_square() {
+        let forest = Forest::parse(Input::from_text("30373\n25512\n65332")).unwrap();
+        assert_eq!((forest.0.rows, forest.0.cols), (3, 5));
+        assert_eq!(num_visible_performant(&forest), 14);
+    }
+}
@@ -0,0 +1,123 @@
+use aoc2022::{Context, Input, ParseError, Solution, parse_pair, grid::{Dir, SparseGrid}, point::Point, render::Renderer};
+use num_traits::{abs, signum};
+
+struct Rope {
+    knots: Vec<Point<2>>,
+}
+
+impl Rope {
+    fn new(size: usize) -> Self {
+        assert!(size >= 2);
+        Self { knots: vec![Point([0,0]);size] }
+    }
+     
+    fn pull(&mut self, dir: Dir) -> Point<2> {
+        // Move the head in the right direction, no restrictions apply.
+        self.knots[0] = self.knots[0].step(dir);
+
+        for idx in 1..self.knots.len() {
+            // Consider each pair of knots acting on eachother
+            let head = self.knots[idx-1];
+            let tail = &mut self.knots[idx];
+
+            // Calculate the current offset between moved head and non-moved tail
+            // If distance in either direction is <= 1, the tail doesn't move.
+            // Else, the tail moves 1 position over in both directions.
+            let mut offset = *tail - head;
+            if abs(offset.0[0]) > 1 || abs(offset.0[1]) > 1 {
+                offset.0[0] -= signum(offset.0[0]);
+                offset.0[1] -= signum(offset.0[1]);
+                *tail = head + offset;
+            }
+        }
+
+        //self.display();
+
+        // Return tail for easy collection of tail positions.
+        self.knots
+            .iter()
+            .last()
+            .cloned()
+            .unwrap()
+    }
+
+    #[allow(dead_code)]
+    fn display(&self) {
+        // y grows downwards like the rows; the first knot on a position is shown
+        let mut grid = SparseGrid::new('.');
+        grid.include(-5, 5);
+        grid.set(0, 0, 's');
+        for (n, knot) in self.knots.iter().enumerate().rev() {
+            let c = match n {
+                0                              => 'H',
+                n if n == self.knots.len() - 1 => 'T',
+                n                              => char::from_digit(n as u32, 36).unwrap_or('?'),
+            };
+            grid.set(knot.0[1], knot.0[0], c);
+        }
+        println!("{}", Renderer::new().grid(&grid, |&c| c.into()));
+    }
+}
+
+fn parse_line(line: &str) -> Result<impl Iterator<Item=Dir>, ParseError> {
+    let (dir, count) = parse_pair::<Dir,usize>(line, " ")?;
+    Ok(std::iter::repeat_n(dir, count))
+}
+
+fn count_visited(directions: &[Dir], rope_knots: usize) -> usize {
+    let mut rope = Rope::new(rope_knots);
+    let mut visited = SparseGrid::new('.');
+    for dir in directions {
+        let tail = rope.pull(*dir);
+        visited.set(tail.0[1], tail.0[0], '#');
+    }
+    //println!("{}", Renderer::new().grid(&visited, |&c| c.into()));
+
+    visited.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Parsed = Vec<Dir>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: Input, _ctx: &Context) -> Result<Vec<Dir>, ParseError> {
+        Ok(input
+            .parse_lines(parse_line)?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+
+    fn part1(directions: &Vec<Dir>, _ctx: &Context) -> usize {
+        count_visited(directions, 2)
+    }
+
+    fn part2(directions: &Vec<Dir>, _ctx: &Context) -> usize {
+        count_visited(directions, 10)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2022::test::{test_example, test_puzzle, test_file};
+
+    #[test]
+    fn example() {
+        test_example::<super::Day9>();
+    }
+
+    #[test]
+    fn example2() {
+        test_file::<super::Day9>(Some("test2"));
+    }
+
+    #[test]
+    fn puzzle() {
+        test_puzzle::<super::Day9>();
+    }
+}
\ No newline at end of file
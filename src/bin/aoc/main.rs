@@ -0,0 +1,128 @@
+use std::{env, process};
+
+use aoc2022::{Context, Day, RunOptions, RUNNER_OPTIONS, options, run_and_print};
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+
+const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+    Day::of::<day7::Day7>(),
+    Day::of::<day8::Day8>(),
+    Day::of::<day9::Day9>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+];
+
+/// Selects the registered days matching the given selection, which is one of:
+/// - "all" for all days
+/// - a single day, eg: "15"
+/// - a range of days, eg: "3..9", "..5" or "10..", which includes the last day ("3..=9" is the same as "3..9")
+fn select(selection: &str) -> Option<Vec<&'static Day>> {
+    let (from, to) = if selection == "all" {
+        (u32::MIN, u32::MAX)
+    } else if let Some((from, to)) = selection.split_once("..") {
+        let from = if from.is_empty() { u32::MIN } else { from.parse().ok()? };
+        // both ends are included, also without '='
+        let to = to.strip_prefix('=').unwrap_or(to);
+        let to = if to.is_empty() { u32::MAX } else { to.parse().ok()? };
+        (from, to)
+    } else {
+        let day = selection.parse().ok()?;
+        (day, day)
+    };
+
+    let days: Vec<_> = DAYS.iter().filter(|day| (from..=to).contains(&day.day)).collect();
+    if days.is_empty() { None } else { Some(days) }
+}
+
+const USAGE: &str = "usage: aoc <day|all|from..to> [options]";
+
+fn print_help(days: &[&Day]) {
+    println!("{}", USAGE);
+    println!();
+    println!("options:");
+    print!("{}", options::help(RUNNER_OPTIONS));
+    for day in days.iter().filter(|day| !day.options.is_empty()) {
+        println!();
+        println!("options of day{}:", day.day);
+        print!("{}", options::help(day.options));
+    }
+}
+
+fn exit_with_usage(msg: &str) -> ! {
+    eprintln!("error: {}", msg);
+    eprintln!("{}", USAGE);
+    eprintln!("run 'aoc --help' for the available options");
+    process::exit(2);
+}
+
+fn main() {
+    // Parse with the options of all days to tell option-values from the positional selection,
+    // then check the given options are supported by the selected days.
+    let all_options = RUNNER_OPTIONS.iter().chain(DAYS.iter().flat_map(|day| day.options));
+    let (ctx, positional) = Context::parse(env::args().skip(1), all_options)
+        .unwrap_or_else(|err| exit_with_usage(&err.to_string()));
+
+    let days = match &positional[..] {
+        [] if ctx.flag("help") => DAYS.iter().collect(),
+        [selection] => select(selection)
+            .unwrap_or_else(|| exit_with_usage(&format!("no registered day matches '{}'", selection))),
+        [] => exit_with_usage("missing day selection"),
+        [_, unexpected, ..] => exit_with_usage(&format!("unexpected argument '{}'", unexpected)),
+    };
+
+    if ctx.flag("help") {
+        print_help(&days);
+        return;
+    }
+
+    for name in ctx.names() {
+        let declared = RUNNER_OPTIONS.iter().chain(days.iter().flat_map(|day| day.options)).any(|opt| opt.name == name);
+        if !declared {
+            exit_with_usage(&format!("option '--{}' is not supported by the selected days", name));
+        }
+    }
+
+    let run = RunOptions::from_context(&ctx).unwrap_or_else(|err| exit_with_usage(&err.to_string()));
+    if run.input.is_some() && days.len() > 1 {
+        exit_with_usage("option '--input' requires a single day");
+    }
+
+    let mut failed = false;
+    for day in days {
+        match run_and_print(day, &run, &ctx) {
+            Ok(passed) => failed |= !passed,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                failed = true;
+            },
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
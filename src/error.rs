@@ -0,0 +1,137 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+
+/// Error raised while reading or parsing an input-file.
+///
+/// The error starts out with just a message and gets annotated with its location (column, line and file)
+/// while it propagates up through the parsers, eg:
+///
+/// ```text
+/// inputs/day4:3:5: invalid digit found in string
+///   | 2-4,x-8
+///   |     ^
+/// ```
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub struct ParseError {
+    pub msg: String,
+    pub path: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(msg: impl Into<String>) -> Self {
+        ParseError { msg: msg.into(), path: None, line: None, column: None, text: None }
+    }
+
+    /// Sets the (1-based) column, unless a more specific one was already set.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Shifts the column by the number of characters in front of `inner` in `outer`,
+    /// used when the error was raised while parsing a part of a line.
+    /// The error is returned unchanged when `inner` is not a slice of `outer` (eg: an owned copy).
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let before = (inner.as_ptr() as usize)
+            .checked_sub(outer.as_ptr() as usize)
+            .filter(|&offset| offset + inner.len() <= outer.len())
+            .and_then(|offset| outer.get(..offset));
+        if let Some(before) = before {
+            self.column = Some(self.column.unwrap_or(1) + before.chars().count());
+        }
+        self
+    }
+
+    /// Sets the (1-based) line-number and its contents, unless already set.
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        if self.line.is_none() {
+            self.line = Some(line);
+            self.text = Some(text.to_owned());
+        }
+        self
+    }
+
+    /// Sets the file the error originates from, unless already set.
+    pub fn in_file(mut self, path: &Path) -> Self {
+        self.path.get_or_insert_with(|| path.to_owned());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+            if let Some(column) = self.column {
+                write!(f, "{}:", column)?;
+            }
+        }
+        if self.path.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.msg)?;
+
+        if let Some(text) = &self.text {
+            write!(f, "\n  | {}", text)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  | {:>width$}", '^', width = column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        ParseError::new(err.to_string())
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::{ParseError, parse_pair};
+
+    #[test]
+    fn location() {
+        let line = "2-4,x-8";
+        let err = parse_pair::<usize,usize>(line, ",").unwrap_err();
+        assert_eq!(err.column, Some(1));
+
+        let (_, b) = line.split_once(',').unwrap();
+        let err = parse_pair::<usize,usize>(b, "-").unwrap_err().within(line, b).at_line(3, line);
+        assert_eq!((err.line, err.column), (Some(3), Some(5)));
+
+        let err = err.in_file(Path::new("inputs/day4"));
+        assert_eq!(err.to_string(), "inputs/day4:3:5: invalid digit found in string\n  | 2-4,x-8\n  |     ^");
+        let line = "ä-ö,x-8";
+        let (_, b) = line.split_once(',').unwrap();
+        let err = parse_pair::<usize,usize>(b, "-").unwrap_err();
+        assert_eq!(err.clone().within(line, b).column, Some(5));
+        let copy = b.to_owned();
+        assert_eq!(err.within(line, &copy).column, Some(1));
+    }
+
+    #[test]
+    fn missing_separator() {
+        let err = parse_pair::<usize,usize>("2-4", ",").unwrap_err();
+        assert_eq!(err, ParseError::new("expected separator \",\""));
+    }
+}
@@ -1,37 +1,93 @@
-use std::{fmt::Display, cmp::max};
+use std::{fmt::Display, ops::{Index, IndexMut, Range}};
 
-use num::signum;
+use crate::ParseError;
 
-use crate::OwnedChars;
+mod automaton;
+mod bits;
+mod combine;
+mod dir;
+mod line;
+mod ray;
+mod region;
+mod scan;
+mod search;
+mod sparse;
 
-#[derive(Clone)]
+pub use automaton::{animation, Automaton, Cycle, Neighborhood};
+pub use bits::BitGrid;
+pub use combine::ShapeError;
+pub use dir::Dir;
+pub use line::{LineError, LineIter, LinePoint, Slope};
+pub use ray::Ray;
+pub use region::{Components, Connectivity};
+pub use scan::{Order, PrefixSums};
+pub use search::Paths;
+pub use sparse::{Bounds, SparseGrid};
+
+
+#[derive(Clone,Debug,Eq,Hash,PartialEq)]
 pub struct Grid<T> {
     pub rows: usize,
     pub cols: usize,
     pub data: Vec<T>,
+    /// How cells neighbor each other, see [Grid::neighbors].
+    pub topology: Topology,
 }
 
 impl<T: Clone> Grid<T> {
     pub fn new(rows: usize, cols: usize, elem: T) -> Self {
-        Grid { rows, cols, data: vec![elem; rows * cols]}
+        Grid::from_data(rows, cols, vec![elem; rows * cols])
     }
 }
 
 impl<T> Grid<T> {
     pub fn from_data(rows: usize, cols: usize, data: Vec<T>) -> Self {
-        Grid { rows, cols, data }
+        Grid { rows, cols, data, topology: Topology::Bounded }
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Self {
+        Grid { topology, ..self }
     }
 
     pub fn iter_cells(&self) -> impl Iterator<Item=Cell> + '_ {
         (0..self.rows).flat_map(move |row| (0..self.cols).map(move |col| Cell { row, col }))
     }
 
+    /// The index in `data` of the cell, catching columns that would wrap to the next row in debug-builds.
+    fn index_of(&self, row: usize, col: usize) -> usize {
+        debug_assert!(col < self.cols, "column {} out of range for grid with {} columns", col, self.cols);
+        row * self.cols + col
+    }
+
+    pub fn contains(&self, cell: Cell) -> bool {
+        cell.row < self.rows && cell.col < self.cols
+    }
+
     pub fn get(&self, row: usize, col: usize) -> &T {
-        &self.data[row * self.cols + col]
+        &self.data[self.index_of(row, col)]
     }
     
     pub fn get_mut(&mut self, row: usize, col: usize) -> &mut T {
-        &mut self.data[row * self.cols + col]
+        let idx = self.index_of(row, col);
+        &mut self.data[idx]
+    }
+
+    /// Like [Grid::get], but returns None for cells outside of the grid.
+    pub fn get_checked(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols { Some(self.get(row, col)) } else { None }
+    }
+
+    pub fn get_checked_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols { Some(self.get_mut(row, col)) } else { None }
+    }
+
+    /// Lookup by signed coordinates, eg: a cell plus an offset. Returns None for cells outside of the grid.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        self.get_checked(usize::try_from(row).ok()?, usize::try_from(col).ok()?)
+    }
+
+    pub fn get_signed_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        self.get_checked_mut(usize::try_from(row).ok()?, usize::try_from(col).ok()?)
     }
     
     pub fn data(&self) -> impl Iterator<Item=&T> {
@@ -39,36 +95,33 @@ impl<T> Grid<T> {
     }
 
     pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item=&T> {
+        debug_assert!(row < self.rows, "row {} out of range for grid with {} rows", row, self.rows);
         self.data[row * self.cols..(row+1)*self.cols].iter()
     }
 
     pub fn row_mut(&mut self, row: usize) -> impl DoubleEndedIterator<Item=&mut T> {
+        debug_assert!(row < self.rows, "row {} out of range for grid with {} rows", row, self.rows);
         self.data[row * self.cols..(row+1)*self.cols].iter_mut()
     }
     
     pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item=&T> {
+        debug_assert!(col < self.cols, "column {} out of range for grid with {} columns", col, self.cols);
         self.data.iter().skip(col).step_by(self.cols)
     }
     
     pub fn col_mut(&mut self, col: usize) -> impl DoubleEndedIterator<Item=&mut T> {
+        debug_assert!(col < self.cols, "column {} out of range for grid with {} columns", col, self.cols);
         self.data.iter_mut().skip(col).step_by(self.cols)
     }
     
+    /// The neighbors of the cell in the topology of the grid, with or without the diagonal ones.
     pub fn neighbors(&self, row: usize, col: usize, diag: bool) -> NeighborIter {
-        let (row, col)   = (row as isize, col as isize);
-        let (rows, cols) = (self.rows as isize, self.cols as isize);
-        let coords = if diag {
-            vec!((row-1,col-1),(row,col-1),(row+1,col-1),(row-1,col),(row+1,col),(row-1,col+1),(row,col+1),(row+1,col+1))
-        } else {
-            vec!((row-1,col),(row+1,col),(row,col-1),(row,col+1))
-        };
+        NeighborIter::new(self.rows, self.cols, row, col, diag, self.topology)
+    }
 
-        NeighborIter(coords
-            .into_iter()
-            .filter(|(row, col)| *row >= 0 && *row < rows && *col >= 0 && *col < cols)
-            .map(|(row,col)| Cell{row: row as usize, col: col as usize})
-            .collect()
-        )
+    /// Like [Grid::neighbors], but in the given topology instead of the one of the grid.
+    pub fn neighbors_in(&self, row: usize, col: usize, diag: bool, topology: Topology) -> NeighborIter {
+        NeighborIter::new(self.rows, self.cols, row, col, diag, topology)
     }
 
     pub fn iter_up(&self, row: usize, col: usize) -> impl Iterator<Item = &T> {
@@ -88,6 +141,118 @@ impl<T> Grid<T> {
     }
 }
 
+impl<T: Clone> Grid<T> {
+    /// Builds a grid of the given size, taking each cell from the (row, col) of this grid returned by `from`.
+    fn transformed(&self, rows: usize, cols: usize, from: impl Fn(usize, usize) -> (usize, usize)) -> Grid<T> {
+        let data = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(|(row, col)| {
+                let (row, col) = from(row, col);
+                self.get(row, col).clone()
+            })
+            .collect();
+        Grid::from_data(rows, cols, data).with_topology(self.topology)
+    }
+
+    /// Mirrors the grid in its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        self.transformed(self.cols, self.rows, |row, col| (col, row))
+    }
+
+    /// Rotates the grid clockwise, the left column becomes the top row.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        self.transformed(self.cols, self.rows, |row, col| (self.rows - 1 - col, row))
+    }
+
+    /// Rotates the grid counter-clockwise, the top row becomes the left column.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        self.transformed(self.cols, self.rows, |row, col| (col, self.cols - 1 - row))
+    }
+
+    /// Mirrors the grid horizontally, left becomes right.
+    pub fn flip_h(&self) -> Grid<T> {
+        self.transformed(self.rows, self.cols, |row, col| (row, self.cols - 1 - col))
+    }
+
+    /// Mirrors the grid vertically, top becomes bottom.
+    pub fn flip_v(&self) -> Grid<T> {
+        self.transformed(self.rows, self.cols, |row, col| (self.rows - 1 - row, col))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Borrows the rectangular part of the grid at the given rows and columns.
+    pub fn view(&self, rows: Range<usize>, cols: Range<usize>) -> GridView<'_, T> {
+        assert!(rows.end <= self.rows && cols.end <= self.cols, "view {:?}x{:?} exceeds grid of {}x{}", rows, cols, self.rows, self.cols);
+        GridView { grid: self, rows, cols }
+    }
+}
+
+/// A borrowed rectangular part of a grid, see [Grid::view].
+/// Cells are addressed relative to the top-left of the view.
+#[derive(Clone,Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols.len()
+    }
+
+    pub fn iter_cells(&self) -> impl Iterator<Item=Cell> + '_ {
+        (0..self.rows()).flat_map(move |row| (0..self.cols()).map(move |col| Cell { row, col }))
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &'a T {
+        debug_assert!(row < self.rows() && col < self.cols(), "cell ({}, {}) out of range for view", row, col);
+        self.grid.get(self.rows.start + row, self.cols.start + col)
+    }
+
+    pub fn get_checked(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.rows() && col < self.cols() { Some(self.get(row, col)) } else { None }
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item=&'a T> {
+        debug_assert!(row < self.rows(), "row {} out of range for view with {} rows", row, self.rows());
+        let start = (self.rows.start + row) * self.grid.cols;
+        self.grid.data[start + self.cols.start..start + self.cols.end].iter()
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item=&'a T> {
+        debug_assert!(col < self.cols(), "column {} out of range for view with {} columns", col, self.cols());
+        self.grid.data
+            .get(self.rows.start * self.grid.cols + self.cols.start + col..)
+            .unwrap_or_default()
+            .iter()
+            .step_by(self.grid.cols)
+            .take(self.rows())
+    }
+
+    /// The neighbors of the cell within the view, in the topology of the grid.
+    pub fn neighbors(&self, row: usize, col: usize, diag: bool) -> NeighborIter {
+        NeighborIter::new(self.rows(), self.cols(), row, col, diag, self.grid.topology)
+    }
+
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        Grid::from_data(self.rows(), self.cols(), (0..self.rows()).flat_map(|row| self.row(row).cloned()).collect())
+    }
+}
+
+impl<T> Index<Cell> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, cell: Cell) -> &T {
+        self.get(cell.row, cell.col)
+    }
+}
+
 impl<T> Grid<T> where T: PartialEq {
     pub fn find(&self, item: &T) -> Option<Cell> {
         self.data
@@ -97,25 +262,108 @@ impl<T> Grid<T> where T: PartialEq {
     }
 }
 
+impl<T> Index<Cell> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, cell: Cell) -> &T {
+        self.get(cell.row, cell.col)
+    }
+}
+
+impl<T> IndexMut<Cell> for Grid<T> {
+    fn index_mut(&mut self, cell: Cell) -> &mut T {
+        self.get_mut(cell.row, cell.col)
+    }
+}
+
 #[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
 pub struct Cell {
     pub row: usize,
     pub col: usize,
 }
 
-pub struct NeighborIter(Vec<Cell>);
+/// How the cells of a grid neighbor each other.
+#[derive(Clone,Copy,Debug,Default,Eq,Hash,PartialEq)]
+pub enum Topology {
+    /// Cells at the edges have fewer neighbors.
+    #[default]
+    Bounded,
+    /// The edges wrap around, eg: the first column is right of the last column.
+    Toroidal,
+    /// Hexagonal cells in axial coordinates, where rows are r and columns are q. Every cell has six neighbors,
+    /// the diagonals up-right and down-left included, cells at the edges have fewer.
+    Hex,
+}
+
+// (row, col) offsets clockwise, starting at the cell above
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const ALL: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+const HEX: [(isize, isize); 6] = [(-1, 0), (-1, 1), (0, 1), (1, 0), (1, -1), (0, -1)];
+
+/// The neighbors of a cell, clockwise starting at the cell above, without allocating.
+/// In toroidal grids of less than three rows or columns, neighbors wrapping around onto the same cell
+/// are yielded once, the cell itself never is.
+#[derive(Clone,Debug)]
+pub struct NeighborIter {
+    cells: [Cell; 8],
+    len: usize,
+    next: usize,
+}
+
+impl NeighborIter {
+    fn new(rows: usize, cols: usize, row: usize, col: usize, diag: bool, topology: Topology) -> Self {
+        let offsets: &[(isize, isize)] = match topology {
+            Topology::Hex => &HEX,
+            _ if diag     => &ALL,
+            _             => &ORTHOGONAL,
+        };
+
+        let mut iter = NeighborIter { cells: [Cell { row: 0, col: 0 }; 8], len: 0, next: 0 };
+        if rows == 0 || cols == 0 {
+            return iter;
+        }
+        let origin = Cell { row, col };
+        let (rows, cols) = (rows as isize, cols as isize);
+        for (drow, dcol) in offsets {
+            let (row, col) = (row as isize + drow, col as isize + dcol);
+            let (row, col) = match topology {
+                Topology::Toroidal                                    => (row.rem_euclid(rows), col.rem_euclid(cols)),
+                _ if row >= 0 && row < rows && col >= 0 && col < cols => (row, col),
+                _                                                     => continue,
+            };
+            let cell = Cell { row: row as usize, col: col as usize };
+            if cell != origin && !iter.cells[..iter.len].contains(&cell) {
+                iter.cells[iter.len] = cell;
+                iter.len += 1;
+            }
+        }
+        iter
+    }
+}
 
 impl Iterator for NeighborIter {
     type Item = Cell;
+
     fn next(&mut self) -> Option<Cell> {
-        self.0.pop()
+        let cell = self.cells[self.next..self.len].first().copied();
+        self.next += cell.is_some() as usize;
+        cell
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len - self.next, Some(self.len - self.next))
     }
 }
 
+impl ExactSizeIterator for NeighborIter {}
+
 impl<T> Display for Grid<T>
 where T: Display
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if self.cols == 0 {
+            return Ok(());
+        }
         for chunk in self.data.chunks(self.cols) {
             for item in chunk {
                 write!(f, "{}", item)?;
@@ -127,69 +375,178 @@ where T: Display
 }
 
 impl<T> Grid<T> {
-    pub fn from_lines<F>(lines: impl Iterator<Item=String>, f: F) -> Grid<T>
+    /// Builds a grid from lines of characters, mapping each with the given function.
+    /// The lines are expected to be of equal length, see [Grid::from_lines_checked] for parsing unvalidated input.
+    pub fn from_lines<'a,F>(lines: impl Iterator<Item=&'a str>, f: F) -> Grid<T>
     where F: Fn(char) -> T
     {
         let mut lines = lines.peekable();
-        let cols = lines.peek().unwrap().len();
-        let data: Vec<T> = lines.flat_map(OwnedChars::from_string).map(f).collect();
+        let cols = lines.peek().unwrap().chars().count();
+        let data: Vec<T> = lines.flat_map(str::chars).map(f).collect();
 
         Grid::from_data(data.len() / cols, cols, data)
     }
-}
-pub enum Dir {
-    Up, Down, Left, Right,
-    UpLeft, UpRight, DownLeft, DownRight,
-}
-
-impl Dir {
-    pub fn between(from: &Cell, to: &Cell) -> Option<Self> {
-        use Dir::*;
-        match (to.row as isize - from.row as isize, to.col as isize - from.col as isize) {
-            (-1, 0) => Some(Up),
-            (-1, 1) => Some(UpRight),
-            ( 0, 1) => Some(Right),
-            ( 1, 1) => Some(DownRight),
-            ( 1, 0) => Some(Down),
-            ( 1,-1) => Some(DownLeft),
-            ( 0,-1) => Some(Left),
-            (-1,-1) => Some(UpLeft),
-            _       => None,
+
+    /// Like [Grid::from_lines], but checks the lines are of equal length, see [Grid::from_str_grid].
+    pub fn from_lines_checked<'a,F>(lines: impl Iterator<Item=&'a str>, first: usize, mut f: F) -> Result<Grid<T>, ParseError>
+    where F: FnMut(char) -> T
+    {
+        Grid::from_str_grid(lines, first, |c| Ok(f(c)))
+    }
+
+    /// Parses a grid of characters from the lines up to the first blank line, parsing each with the given function,
+    /// eg: from [crate::Input::lines] or the lines of a [crate::Group].
+    /// All lines should have the same number of characters, errors point at the offending line and column,
+    /// counting from `first` as the (1-based) line-number of the first line.
+    pub fn from_str_grid<'a,F>(lines: impl Iterator<Item=&'a str>, first: usize, mut f: F) -> Result<Grid<T>, ParseError>
+    where F: FnMut(char) -> Result<T, ParseError>
+    {
+        let mut cols: Option<usize> = None;
+        let mut data = Vec::new();
+        let mut rows = 0;
+        for line in lines.take_while(|line| !line.is_empty()) {
+            let start = data.len();
+            for (i, c) in line.chars().enumerate() {
+                data.push(f(c).map_err(|err| err.at_column(i + 1).at_line(first + rows, line))?);
+            }
+            let count = data.len() - start;
+            if let Some(expected) = cols.filter(|&expected| expected != count) {
+                return Err(ParseError::new(format!("expected {} columns, got {}", expected, count))
+                    .at_column(expected.min(count) + 1)
+                    .at_line(first + rows, line));
+            }
+            cols = Some(count);
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Grid::from_data(rows, cols, data)),
+            _                      => Err(ParseError::new("empty grid")),
         }
     }
 }
 
-pub struct LineIter {
-    next: Cell,
-    step: (isize, isize),
-    count: usize,
+impl Grid<u32> {
+    /// Parses a grid of single digits, eg: the heights of trees, see [Grid::from_str_grid].
+    pub fn parse_digits<'a>(lines: impl Iterator<Item=&'a str>, first: usize) -> Result<Self, ParseError> {
+        Grid::from_str_grid(lines, first, |c| c.to_digit(10).ok_or_else(|| ParseError::new(format!("expected digit, got {:?}", c))))
+    }
 }
 
-impl LineIter {
-    pub fn new(from: Cell, to: Cell) -> Option<Self> {
-        let step = (signum(to.row as isize - from.row as isize), signum(to.col as isize - from.col as isize));
-        let count = max(to.row.abs_diff(from.row), to.col.abs_diff(from.col)) + 1;
-        if step.0 != 0 && step.1 != 0 {
-            return None
-        }
+#[cfg(test)]
+mod test {
+    use super::{Cell, Connectivity, Grid, Order, Topology};
+    use crate::Input;
 
-        Some(LineIter { next: from, step, count })
+    /// A grid of the characters of the lines, eg: walls and open cells, for the tests of the grid modules.
+    pub(crate) fn chars(lines: &[&str]) -> Grid<char> {
+        Grid::from_lines(lines.iter().copied(), |c| c)
     }
-}
 
-impl Iterator for LineIter {
-    type Item = Cell;
+    #[test]
+    fn access() {
+        let mut grid = Grid::from_data(2, 3, (0..6).collect());
+        grid[Cell { row: 1, col: 0 }] += 10;
+        assert_eq!(grid[Cell { row: 1, col: 0 }], 13);
+        assert_eq!(grid.get_checked(1, 2), Some(&5));
+        assert_eq!(grid.get_checked(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&4));
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.count == 0 {
-            return None;
-        }
+    #[test]
+    fn parse() {
+        let grid = Grid::parse_digits("123\n456\n\n".lines(), 1).unwrap();
+        assert_eq!((grid.rows, grid.cols, grid.get(1, 0)), (2, 3, &4));
+
+        let grid = Grid::from_lines_checked("äb\ncd\nef".lines(), 1, |c| c).unwrap();
+        assert_eq!((grid.rows, grid.cols, grid.get(0, 1)), (3, 2, &'b'));
 
-        let next = self.next;
-        self.next.row = (self.next.row as isize + self.step.0) as usize;
-        self.next.col = (self.next.col as isize + self.step.1) as usize;
-        self.count -= 1;
+        // the grid ends at a blank line, the rest remains in the input
+        let mut input = Input::from_text("0\n12\n34\n\nrest\n");
+        input.next_line();
+        let first = input.line() + 1;
+        assert_eq!(Grid::parse_digits(input.lines(), first).unwrap(), Grid::from_data(2, 2, vec![1, 2, 3, 4]));
+        assert_eq!(input.next_line(), Some("rest"));
 
-        Some(next)
+        let err = Grid::parse_digits("123\n45\n".lines(), 1).unwrap_err();
+        assert_eq!((err.line, err.column, err.msg.as_str()), (Some(2), Some(3), "expected 3 columns, got 2"));
+        let err = Grid::parse_digits("123\n4x6\n".lines(), 3).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(4), Some(2)));
+        assert!(Grid::parse_digits("".lines(), 1).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn transform() {
+        // 1 2 3
+        // 4 5 6
+        let grid = Grid::from_data(2, 3, (1..=6).collect::<Vec<_>>());
+        assert_eq!(grid.transpose(), Grid::from_data(3, 2, vec![1, 4, 2, 5, 3, 6]));
+        assert_eq!(grid.rotate_cw(), Grid::from_data(3, 2, vec![4, 1, 5, 2, 6, 3]));
+        assert_eq!(grid.rotate_ccw(), Grid::from_data(3, 2, vec![3, 6, 2, 5, 1, 4]));
+        assert_eq!(grid.flip_h(), Grid::from_data(2, 3, vec![3, 2, 1, 6, 5, 4]));
+        assert_eq!(grid.flip_v(), Grid::from_data(2, 3, vec![4, 5, 6, 1, 2, 3]));
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn view() {
+        let grid = Grid::from_data(3, 4, (0..12).collect::<Vec<_>>());
+        let view = grid.view(1..3, 1..3);
+        assert_eq!((view.rows(), view.cols(), view[Cell { row: 0, col: 0 }]), (2, 2, 5));
+        assert_eq!(view.row(1).collect::<Vec<_>>(), vec![&9, &10]);
+        assert_eq!(view.col(1).rev().collect::<Vec<_>>(), vec![&10, &6]);
+        assert_eq!(view.neighbors(0, 0, false).count(), 2);
+        assert_eq!(view.get_checked(0, 2), None);
+        assert_eq!(view.to_grid(), Grid::from_data(2, 2, vec![5, 6, 9, 10]));
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 4, 0);
+        let cells = |iter: super::NeighborIter| iter.map(|cell| (cell.row, cell.col)).collect::<Vec<_>>();
+        assert_eq!(cells(grid.neighbors(0, 0, false)), vec![(0, 1), (1, 0)]);
+        assert_eq!(cells(grid.neighbors(1, 1, true)), vec![(0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0), (1, 0), (0, 0)]);
+        assert_eq!(cells(grid.neighbors_in(0, 0, false, Topology::Toroidal)), vec![(2, 0), (0, 1), (1, 0), (0, 3)]);
+        assert_eq!(cells(grid.neighbors_in(1, 1, true, Topology::Hex)), vec![(0, 1), (0, 2), (1, 2), (2, 1), (2, 0), (1, 0)]);
+
+        let grid = grid.with_topology(Topology::Toroidal);
+        assert_eq!(grid.neighbors(2, 3, true).len(), 8);
+    }
+
+    #[test]
+    fn topology() {
+        // grids derived from a grid keep its topology
+        let grid = chars(&["#.", ".."]).with_topology(Topology::Toroidal);
+        let wall = |&c: &char| c == '#';
+        assert_eq!(grid.flip_v().topology, Topology::Toroidal);
+        assert_eq!(grid.map(wall).topology, Topology::Toroidal);
+        assert_eq!(grid.zip_with(&grid, |a, b| a == b).unwrap().topology, Topology::Toroidal);
+        assert_eq!(grid.fold_cols(0, |walls, c| walls + wall(c) as u32).topology, Topology::Toroidal);
+        assert_eq!(grid.scan_rows(Order::Forward, 0, |walls, c| walls + wall(c) as u32).topology, Topology::Toroidal);
+        assert_eq!(grid.flood_fill([Cell { row: 1, col: 1 }], Connectivity::Four, |c| !wall(c)).topology, Topology::Toroidal);
+        assert_eq!(grid.with_topology(Topology::Hex).rotate_cw().topology, Topology::Hex);
+    }
+
+    #[test]
+    fn small_toroidal() {
+        let cells = |rows, cols, diag| {
+            let grid = Grid::new(rows, cols, 0).with_topology(Topology::Toroidal);
+            grid.neighbors(0, 0, diag).map(|cell| (cell.row, cell.col)).collect::<Vec<_>>()
+        };
+        assert_eq!(cells(1, 4, false), vec![(0, 1), (0, 3)]);
+        assert_eq!(cells(1, 4, true), vec![(0, 1), (0, 3)]);
+        assert_eq!(cells(1, 1, true), vec![]);
+        assert_eq!(cells(2, 2, false), vec![(1, 0), (0, 1)]);
+        assert_eq!(cells(2, 2, true), vec![(1, 0), (1, 1), (0, 1)]);
+        assert_eq!(Grid::<u32>::new(0, 3, 0).with_topology(Topology::Toroidal).neighbors(0, 0, true).count(), 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "column 3 out of range")]
+    fn column_overflow() {
+        let grid = Grid::from_data(2, 3, (0..6).collect::<Vec<_>>());
+        grid.get(0, 3);
+    }
+}
@@ -0,0 +1,192 @@
+use std::{collections::HashMap, hash::Hash};
+
+use super::{Cell, Dir, Grid};
+use crate::render::{Glyph, Renderer};
+
+/// A cell along with the grid around it, as seen by the rule of an [Automaton].
+pub struct Neighborhood<'a, T> {
+    grid: &'a Grid<T>,
+    cell: Cell,
+    diag: bool,
+}
+
+impl<'a, T> Neighborhood<'a, T> {
+    pub fn cell(&self) -> Cell {
+        self.cell
+    }
+
+    pub fn value(&self) -> &'a T {
+        &self.grid[self.cell]
+    }
+
+    /// The values of the neighbors, see [Grid::neighbors].
+    pub fn neighbors(&self) -> impl Iterator<Item=&'a T> + '_ {
+        self.grid.neighbors(self.cell.row, self.cell.col, self.diag).map(|cell| &self.grid[cell])
+    }
+
+    /// The number of neighbors matching the predicate.
+    pub fn count(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.neighbors().filter(|value| pred(value)).count()
+    }
+
+    /// The value of the neighbor in the direction, None at the edge.
+    pub fn get(&self, dir: Dir) -> Option<&'a T> {
+        self.grid.step(self.cell, dir).map(|cell| &self.grid[cell])
+    }
+}
+
+/// Where an automaton starts repeating itself, see [Automaton::find_cycle].
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub struct Cycle {
+    /// The first generation that repeats.
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// The generation within the first pass of the cycle with the same state as the given one,
+    /// eg: to skip ahead a billion generations.
+    pub fn equivalent(&self, generation: usize) -> usize {
+        if generation < self.start {
+            generation
+        } else {
+            self.start + (generation - self.start) % self.len
+        }
+    }
+}
+
+type Rule<'a, T> = Box<dyn Fn(&Neighborhood<T>) -> T + 'a>;
+type Hook<'a, T> = Box<dyn FnMut(usize, &Grid<T>) + 'a>;
+
+/// Simulates a grid generation by generation, where the rule gives the next value of every cell
+/// from its neighborhood in the current generation.
+///
+/// Generations are double-buffered: the rule only sees the current generation, while the next one is written
+/// to a second grid which is swapped in after the step.
+pub struct Automaton<'a, T> {
+    current: Grid<T>,
+    next: Grid<T>,
+    rule: Rule<'a, T>,
+    diag: bool,
+    generation: usize,
+    hook: Option<Hook<'a, T>>,
+}
+
+impl<'a, T: Clone + PartialEq> Automaton<'a, T> {
+    /// Starts at generation 0 with the grid, the neighborhood includes the diagonal neighbors if `diag` is set.
+    pub fn new(grid: Grid<T>, diag: bool, rule: impl Fn(&Neighborhood<T>) -> T + 'a) -> Self {
+        let next = grid.clone();
+        Automaton { current: grid, next, rule: Box::new(rule), diag, generation: 0, hook: None }
+    }
+
+    /// Calls the hook with the generation and grid after every step, eg: an [animation].
+    pub fn on_step(mut self, hook: impl FnMut(usize, &Grid<T>) + 'a) -> Self {
+        self.hook = Some(Box::new(hook));
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// The number of steps taken.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances one generation, returns whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut changed = false;
+        for cell in self.current.iter_cells() {
+            let value = (self.rule)(&Neighborhood { grid: &self.current, cell, diag: self.diag });
+            changed |= value != self.current[cell];
+            self.next[cell] = value;
+        }
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+
+        if let Some(hook) = &mut self.hook {
+            hook(self.generation, &self.current);
+        }
+        changed
+    }
+
+    pub fn step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    /// Steps until a step changes nothing, taking at most `max` steps, returns whether it became stable.
+    /// The generation reached includes that last step. Oscillators never become stable, see [Automaton::find_cycle].
+    pub fn step_until_stable(&mut self, max: usize) -> bool {
+        (0..max).any(|_| !self.step())
+    }
+
+    /// Steps until a state repeats one of an earlier generation, which ends the search.
+    /// Stable states are cycles of length 1.
+    pub fn find_cycle(&mut self) -> Cycle where T: Eq + Hash {
+        let mut seen = HashMap::new();
+        loop {
+            if let Some(&start) = seen.get(&self.current) {
+                return Cycle { start, len: self.generation - start };
+            }
+            seen.insert(self.current.clone(), self.generation);
+            self.step();
+        }
+    }
+}
+
+/// A hook for [Automaton::on_step] that redraws the grid on every step.
+pub fn animation<'a, T>(renderer: Renderer, glyph: impl Fn(&T) -> Glyph + 'a) -> impl FnMut(usize, &Grid<T>) + 'a {
+    move |generation, grid| {
+        print!("\x1B[2J\x1B[H");
+        println!("Generation {}:", generation);
+        print!("{}", renderer.grid(grid, &glyph));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell as Counter;
+
+    use super::{Automaton, Cycle, Neighborhood};
+    use crate::grid::test::chars;
+
+    fn life(cell: &Neighborhood<char>) -> char {
+        match (cell.value(), cell.count(|&c| c == '#')) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _                       => '.',
+        }
+    }
+
+    #[test]
+    fn blinker() {
+        let start = chars(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let mut life = Automaton::new(start.clone(), true, life);
+        assert!(life.step());
+        assert_eq!(life.grid(), &chars(&[".....", ".....", ".###.", ".....", "....."]));
+        life.step_n(3);
+        assert_eq!((life.generation(), life.grid()), (4, &start));
+
+        let cycle = life.find_cycle();
+        assert_eq!(cycle, Cycle { start: 4, len: 2 });
+        assert_eq!(cycle.equivalent(1_000_000_001), 5);
+        assert!(!life.step_until_stable(10));
+    }
+
+    #[test]
+    fn stable() {
+        let steps = Counter::new(0);
+        let start = chars(&["....", ".##.", ".#..", "...."]);
+        let mut life = Automaton::new(start, true, life).on_step(|_, _| steps.set(steps.get() + 1));
+        assert!(life.step_until_stable(10));
+        assert_eq!(life.generation(), 2);
+        assert_eq!(life.into_grid(), chars(&["....", ".##.", ".##.", "...."]));
+        assert_eq!(steps.get(), 2);
+    }
+}
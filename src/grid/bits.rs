@@ -0,0 +1,175 @@
+use std::{fmt::Display, ops::{BitAnd, BitOr, BitXor}};
+
+use super::{Cell, Grid};
+use crate::render::Renderer;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans stored as one bit per cell, eg: for visited cells or visibility masks.
+#[derive(Clone,Debug,Eq,Hash,PartialEq)]
+pub struct BitGrid {
+    pub rows: usize,
+    pub cols: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid with all cells unset.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        BitGrid { rows, cols, words: vec![0; (rows * cols).div_ceil(BITS)] }
+    }
+
+    /// The word and mask of the bit of the cell.
+    fn bit(&self, row: usize, col: usize) -> (usize, u64) {
+        assert!(row < self.rows && col < self.cols, "cell ({}, {}) out of range for grid of {}x{}", row, col, self.rows, self.cols);
+        let idx = row * self.cols + col;
+        (idx / BITS, 1 << (idx % BITS))
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        let (word, mask) = self.bit(row, col);
+        self.words[word] & mask != 0
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        let (word, mask) = self.bit(row, col);
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    /// Sets the cell and returns whether it was unset before, like [std::collections::HashSet::insert].
+    pub fn insert(&mut self, cell: Cell) -> bool {
+        let was_set = self.get(cell.row, cell.col);
+        self.set(cell.row, cell.col, true);
+        !was_set
+    }
+
+    /// The number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn row(&self, row: usize) -> impl DoubleEndedIterator<Item=bool> + '_ {
+        (0..self.cols).map(move |col| self.get(row, col))
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item=bool> + '_ {
+        (0..self.rows).map(move |row| self.get(row, col))
+    }
+
+    /// Iterates the set cells, row by row.
+    pub fn ones(&self) -> impl Iterator<Item=Cell> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &word)| {
+                (0..BITS).filter(move |bit| word & (1 << bit) != 0).map(move |bit| i * BITS + bit)
+            })
+            .map(|idx| Cell { row: idx / self.cols, col: idx % self.cols })
+    }
+
+    /// Combines the bits of both grids, which should be of the same size.
+    fn combine(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> BitGrid {
+        assert!((self.rows, self.cols) == (other.rows, other.cols),
+            "grids of {}x{} and {}x{} differ in size", self.rows, self.cols, other.rows, other.cols);
+        let words = self.words.iter().zip(&other.words).map(|(&a, &b)| f(a, b)).collect();
+        BitGrid { rows: self.rows, cols: self.cols, words }
+    }
+
+    /// The cells set in either grid.
+    pub fn union(&self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// The cells set in both grids.
+    pub fn intersection(&self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// The cells set in exactly one of the grids.
+    pub fn xor(&self, other: &BitGrid) -> BitGrid {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::from(self)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+    fn bitor(self, other: &BitGrid) -> BitGrid {
+        self.union(other)
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+    fn bitand(self, other: &BitGrid) -> BitGrid {
+        self.intersection(other)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+    fn bitxor(self, other: &BitGrid) -> BitGrid {
+        self.xor(other)
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new(grid.rows, grid.cols);
+        for cell in grid.iter_cells().filter(|&cell| grid[cell]) {
+            bits.set(cell.row, cell.col, true);
+        }
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        let data = (0..bits.rows).flat_map(|row| bits.row(row)).collect();
+        Grid::from_data(bits.rows, bits.cols, data)
+    }
+}
+
+/// Renders set cells as '#' and others as '.'.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = Renderer::new().grid(self, |&set| if set { '#' } else { '.' }.into());
+        write!(f, "{}", text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BitGrid;
+    use crate::grid::{Cell, Grid};
+
+    #[test]
+    fn bits() {
+        let mut bits = BitGrid::new(3, 30);
+        bits.set(0, 0, true);
+        bits.set(2, 29, true);
+        assert!(bits.insert(Cell { row: 2, col: 5 }));
+        assert!(!bits.insert(Cell { row: 2, col: 5 }));
+        assert_eq!((bits.get(2, 29), bits.get(1, 29), bits.count_ones()), (true, false, 3));
+        assert_eq!(bits.ones().collect::<Vec<_>>(), vec![Cell { row: 0, col: 0 }, Cell { row: 2, col: 5 }, Cell { row: 2, col: 29 }]);
+        assert_eq!(bits.col(29).collect::<Vec<_>>(), vec![false, false, true]);
+        bits.set(0, 0, false);
+        assert_eq!(bits.row(0).filter(|&set| set).count(), 0);
+    }
+
+    #[test]
+    fn combine() {
+        let a = BitGrid::from(&Grid::from_data(2, 2, vec![true, true, false, false]));
+        let b = BitGrid::from(&Grid::from_data(2, 2, vec![true, false, true, false]));
+        assert_eq!((&a | &b).to_grid(), Grid::from_data(2, 2, vec![true, true, true, false]));
+        assert_eq!((&a & &b).count_ones(), 1);
+        assert_eq!(a.xor(&b).to_string(), ".#\n#.\n");
+    }
+}
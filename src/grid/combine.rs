@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use super::{Cell, Grid};
+
+/// Grids that should be combined cell by cell differ in size, given as (rows, cols).
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub struct ShapeError {
+    pub expected: (usize, usize),
+    pub found: (usize, usize),
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected grid of {}x{}, found {}x{}", self.expected.0, self.expected.1, self.found.0, self.found.1)
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+impl<T> Grid<T> {
+    fn check_shape<U>(&self, other: &Grid<U>) -> Result<(), ShapeError> {
+        match ((self.rows, self.cols), (other.rows, other.cols)) {
+            (expected, found) if expected != found => Err(ShapeError { expected, found }),
+            _                                      => Ok(()),
+        }
+    }
+
+    /// A grid of the same size and topology with every value mapped.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::from_data(self.rows, self.cols, self.data.iter().map(f).collect()).with_topology(self.topology)
+    }
+
+    /// Like [Grid::map], also passing the cell of every value.
+    pub fn map_with_cell<U>(&self, f: impl Fn(Cell, &T) -> U) -> Grid<U> {
+        let data = self.iter_cells().map(|cell| f(cell, &self[cell])).collect();
+        Grid::from_data(self.rows, self.cols, data).with_topology(self.topology)
+    }
+
+    /// Combines the values of the same cells of both grids, which should be of the same size.
+    pub fn zip_with<U, V>(&self, other: &Grid<U>, f: impl Fn(&T, &U) -> V) -> Result<Grid<V>, ShapeError> {
+        self.check_shape(other)?;
+        let data = self.data.iter().zip(&other.data).map(|(a, b)| f(a, b)).collect();
+        Ok(Grid::from_data(self.rows, self.cols, data).with_topology(self.topology))
+    }
+
+    /// Combines the values of the same cells of any number of grids, which should all be of the same size.
+    /// Gives an empty grid when there are no grids.
+    pub fn zip_n<U>(grids: &[&Grid<T>], f: impl Fn(&[&T]) -> U) -> Result<Grid<U>, ShapeError> {
+        let Some(first) = grids.first() else {
+            return Ok(Grid::from_data(0, 0, Vec::new()));
+        };
+        for grid in grids {
+            first.check_shape(grid)?;
+        }
+
+        let mut values = Vec::with_capacity(grids.len());
+        let data = (0..first.data.len())
+            .map(|i| {
+                values.clear();
+                values.extend(grids.iter().map(|grid| &grid.data[i]));
+                f(&values)
+            })
+            .collect();
+        Ok(Grid::from_data(first.rows, first.cols, data).with_topology(first.topology))
+    }
+
+    /// Folds every row from left to right, giving a single column with one value per row.
+    pub fn fold_rows<A: Clone>(&self, init: A, f: impl Fn(A, &T) -> A) -> Grid<A> {
+        let data = (0..self.rows).map(|row| self.row(row).fold(init.clone(), &f)).collect();
+        Grid::from_data(self.rows, 1, data).with_topology(self.topology)
+    }
+
+    /// Folds every column from top to bottom, giving a single row with one value per column.
+    pub fn fold_cols<A: Clone>(&self, init: A, f: impl Fn(A, &T) -> A) -> Grid<A> {
+        let data = (0..self.cols).map(|col| self.col(col).fold(init.clone(), &f)).collect();
+        Grid::from_data(1, self.cols, data).with_topology(self.topology)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ShapeError;
+    use crate::grid::{Cell, Grid, test::chars};
+
+    fn digits(lines: [&str; 2]) -> Grid<u32> {
+        Grid::parse_digits(lines.into_iter(), 1).unwrap()
+    }
+
+    #[test]
+    fn map() {
+        let grid = chars(&["#..", ".#."]);
+        assert_eq!(grid.map(|&c| c == '#'), Grid::from_data(2, 3, vec![true, false, false, false, true, false]));
+        assert_eq!(grid.map_with_cell(|cell, &c| (c == '#').then_some(cell.col))[Cell { row: 1, col: 1 }], Some(1));
+    }
+
+    #[test]
+    fn zip() {
+        let (a, b, c) = (digits(["12", "34"]), digits(["50", "06"]), digits(["27", "18"]));
+        assert_eq!(a.zip_with(&b, |a, b| a + b), Ok(Grid::from_data(2, 2, vec![6, 2, 3, 10])));
+        assert_eq!(a.zip_with(&Grid::new(1, 4, 0), |a, b| a + b), Err(ShapeError { expected: (2, 2), found: (1, 4) }));
+
+        let max = Grid::zip_n(&[&a, &b, &c], |values| **values.iter().max().unwrap()).unwrap();
+        assert_eq!(max, Grid::from_data(2, 2, vec![5, 7, 3, 8]));
+        assert!(Grid::zip_n(&[&a, &Grid::new(1, 4, 0)], |_| 0).is_err());
+    }
+
+    #[test]
+    fn fold() {
+        let grid = digits(["2036", "4105"]);
+        assert_eq!(grid.fold_rows(0, |sum, n| sum + n), Grid::from_data(2, 1, vec![11, 10]));
+        assert_eq!(grid.fold_cols(1, |product, n| product * n), Grid::from_data(1, 4, vec![8, 0, 0, 30]));
+    }
+}
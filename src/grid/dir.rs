@@ -0,0 +1,185 @@
+use std::str::FromStr;
+
+use super::{Bounds, Cell, Grid, Topology};
+use crate::{ParseError, point::{Offset, Point}};
+
+/// A direction on the grid, where up is towards the first row.
+#[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
+pub enum Dir {
+    Up, Down, Left, Right,
+    UpLeft, UpRight, DownLeft, DownRight,
+}
+
+impl Dir {
+    /// The horizontal and vertical directions, clockwise starting at up.
+    pub const ORTHOGONAL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// All directions, clockwise starting at up.
+    pub const ALL: [Dir; 8] = [
+        Dir::Up, Dir::UpRight, Dir::Right, Dir::DownRight,
+        Dir::Down, Dir::DownLeft, Dir::Left, Dir::UpLeft,
+    ];
+
+    /// Iterates the horizontal and vertical directions, see [Dir::ORTHOGONAL].
+    pub fn orthogonal() -> impl Iterator<Item=Dir> {
+        Dir::ORTHOGONAL.into_iter()
+    }
+
+    /// Iterates all directions, see [Dir::ALL].
+    pub fn all() -> impl Iterator<Item=Dir> {
+        Dir::ALL.into_iter()
+    }
+
+    pub fn between(from: &Cell, to: &Cell) -> Option<Self> {
+        let offset = (to.row as isize - from.row as isize, to.col as isize - from.col as isize);
+        Dir::all().find(|dir| dir.offset() == offset)
+    }
+
+    /// The (row, col) offset of a step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        use Dir::*;
+        match self {
+            Up        => (-1, 0),
+            UpRight   => (-1, 1),
+            Right     => ( 0, 1),
+            DownRight => ( 1, 1),
+            Down      => ( 1, 0),
+            DownLeft  => ( 1,-1),
+            Left      => ( 0,-1),
+            UpLeft    => (-1,-1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let (row, col) = self.offset();
+        row != 0 && col != 0
+    }
+
+    /// Rotates clockwise by the given number of 45° turns, counter-clockwise for negative turns.
+    pub fn rotate45(self, turns: isize) -> Self {
+        let idx = Dir::ALL.iter().position(|&dir| dir == self).unwrap() as isize;
+        Dir::ALL[(idx + turns).rem_euclid(8) as usize]
+    }
+
+    /// Turns 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate45(2)
+    }
+
+    /// Turns 90° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate45(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate45(4)
+    }
+}
+
+/// Parses `U/D/L/R`, compass points (`N/E/S/W`, `NE`, ...) and arrows (`^/v/</>` or `↑/↓/←/→`).
+impl FromStr for Dir {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        use Dir::*;
+        match s {
+            "U" | "N" | "^" | "↑" => Ok(Up),
+            "D" | "S" | "v" | "↓" => Ok(Down),
+            "L" | "W" | "<" | "←" => Ok(Left),
+            "R" | "E" | ">" | "→" => Ok(Right),
+            "NW" | "↖"            => Ok(UpLeft),
+            "NE" | "↗"            => Ok(UpRight),
+            "SW" | "↙"            => Ok(DownLeft),
+            "SE" | "↘"            => Ok(DownRight),
+            _                     => Err(ParseError::new(format!("invalid direction {:?}", s))),
+        }
+    }
+}
+
+impl Cell {
+    /// The neighboring cell in the direction, None when stepping before the first row or column.
+    pub fn step(self, dir: Dir) -> Option<Cell> {
+        let (row, col) = dir.offset();
+        Some(Cell { row: self.row.checked_add_signed(row)?, col: self.col.checked_add_signed(col)? })
+    }
+}
+
+impl<T> Grid<T> {
+    /// The neighboring cell in the direction, None when stepping off the grid.
+    /// Toroidal grids wrap around instead, which leads back to the cell itself in a single row or column.
+    pub fn step(&self, cell: Cell, dir: Dir) -> Option<Cell> {
+        if self.topology != Topology::Toroidal {
+            return cell.step(dir).filter(|&next| self.contains(next));
+        }
+        if self.rows == 0 || self.cols == 0 {
+            return None;
+        }
+        let (row, col) = dir.offset();
+        let row = (cell.row as isize + row).rem_euclid(self.rows as isize) as usize;
+        let col = (cell.col as isize + col).rem_euclid(self.cols as isize) as usize;
+        Some(Cell { row, col })
+    }
+}
+
+/// The (x, y) offset of a step, where y grows downwards like the rows of a grid.
+impl From<Dir> for Offset<2> {
+    fn from(dir: Dir) -> Self {
+        let (row, col) = dir.offset();
+        Offset([col, row])
+    }
+}
+
+impl Point<2> {
+    /// The neighboring (x, y) point in the direction, see [Offset::from].
+    pub fn step(self, dir: Dir) -> Point<2> {
+        self + Offset::from(dir)
+    }
+
+    /// Like [Point::step], but None when stepping outside the bounds, where rows are y and columns are x.
+    pub fn step_within(self, dir: Dir, bounds: &Bounds) -> Option<Point<2>> {
+        let next = self.step(dir);
+        bounds.contains(next[1], next[0]).then_some(next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Dir::{self, *};
+    use crate::{grid::{Bounds, Cell, Grid, Topology}, point::Point};
+
+    #[test]
+    fn rotate() {
+        assert_eq!((Up.turn_right(), Up.turn_left(), Up.opposite()), (Right, Left, Down));
+        assert_eq!((DownLeft.rotate45(1), DownLeft.rotate45(-3)), (Left, Right));
+        assert!(Dir::all().all(|dir| dir.opposite().opposite() == dir && dir.turn_left().turn_right() == dir));
+        assert_eq!(Dir::orthogonal().filter(|dir| dir.is_diagonal()).count(), 0);
+        assert_eq!(Dir::between(&Cell { row: 1, col: 1 }, &Cell { row: 0, col: 2 }), Some(UpRight));
+    }
+
+    #[test]
+    fn parse() {
+        let dirs: Result<Vec<Dir>, _> = ["U", "E", "v", "←", "NW"].iter().map(|s| s.parse()).collect();
+        assert_eq!(dirs.unwrap(), vec![Up, Right, Down, Left, UpLeft]);
+        assert!("X".parse::<Dir>().is_err());
+    }
+
+    #[test]
+    fn step() {
+        let cell = Cell { row: 0, col: 2 };
+        assert_eq!(cell.step(DownLeft), Some(Cell { row: 1, col: 1 }));
+        assert_eq!(cell.step(Up), None);
+
+        let grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.step(cell, Right), None);
+        assert_eq!(grid.step(cell, Left), Some(Cell { row: 0, col: 1 }));
+        let grid = grid.with_topology(Topology::Toroidal);
+        assert_eq!(grid.step(cell, UpRight), Some(Cell { row: 1, col: 0 }));
+        assert_eq!(Grid::new(1, 3, 0).with_topology(Topology::Toroidal).step(cell, Down), Some(cell));
+        assert_eq!(Grid::<u32>::new(0, 0, 0).with_topology(Topology::Toroidal).step(cell, Down), None);
+
+        assert_eq!(Point([0, 0]).step(Up), Point([0, -1]));
+        let bounds = Bounds { min_row: 0, max_row: 1, min_col: 0, max_col: 1 };
+        assert_eq!(Point([1, 0]).step_within(Down, &bounds), Some(Point([1, 1])));
+        assert_eq!(Point([1, 0]).step_within(Right, &bounds), None);
+    }
+}
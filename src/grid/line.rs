@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use super::Cell;
+use crate::point::Point;
+
+/// A point a line can be drawn between, given by its two coordinates.
+pub trait LinePoint: Copy {
+    fn coords(self) -> (isize, isize);
+    fn from_coords(a: isize, b: isize) -> Self;
+}
+
+impl LinePoint for Cell {
+    fn coords(self) -> (isize, isize) {
+        (self.row as isize, self.col as isize)
+    }
+
+    fn from_coords(row: isize, col: isize) -> Self {
+        Cell { row: row as usize, col: col as usize }
+    }
+}
+
+impl LinePoint for Point<2> {
+    fn coords(self) -> (isize, isize) {
+        (self[0], self[1])
+    }
+
+    fn from_coords(x: isize, y: isize) -> Self {
+        Point([x, y])
+    }
+}
+
+/// The lines to accept, see [LineIter::strict].
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Slope {
+    /// Only horizontal and vertical lines.
+    Straight,
+    /// Horizontal, vertical and 45° diagonal lines.
+    Diagonal,
+    /// Any line, approximated by the cells closest to it.
+    Any,
+}
+
+/// Why a line was rejected, along with the distance it spans along both coordinates.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum LineError {
+    NotStraight(usize, usize),
+    NotDiagonal(usize, usize),
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineError::NotStraight(a, b) => write!(f, "line should be horizontal or vertical, it spans {} by {}", a, b),
+            LineError::NotDiagonal(a, b) => write!(f, "line should be horizontal, vertical or at 45°, it spans {} by {}", a, b),
+        }
+    }
+}
+
+impl std::error::Error for LineError {}
+
+/// Iterates the cells on the line between two points, both ends included.
+///
+/// Lines that are not horizontal, vertical or diagonal are rasterized using Bresenham's algorithm,
+/// which takes one step along the longest coordinate for every cell.
+#[derive(Clone,Debug)]
+pub struct LineIter<P = Cell> {
+    next: (isize, isize),
+    step: (isize, isize),
+    delta: (isize, isize),
+    err: isize,
+    count: usize,
+    point: std::marker::PhantomData<P>,
+}
+
+impl<P: LinePoint> LineIter<P> {
+    /// A horizontal, vertical or 45° diagonal line, None for any other line.
+    pub fn new(from: P, to: P) -> Option<Self> {
+        Self::strict(from, to, Slope::Diagonal).ok()
+    }
+
+    /// Any line, see [Slope::Any].
+    pub fn bresenham(from: P, to: P) -> Self {
+        let ((a0, b0), (a1, b1)) = (from.coords(), to.coords());
+        let delta = (a0.abs_diff(a1) as isize, b0.abs_diff(b1) as isize);
+        LineIter {
+            next: (a0, b0),
+            step: ((a1 - a0).signum(), (b1 - b0).signum()),
+            delta,
+            err: delta.1 - delta.0,
+            count: delta.0.max(delta.1) as usize + 1,
+            point: std::marker::PhantomData,
+        }
+    }
+
+    /// A line of the given slope, or the reason it does not have that slope.
+    pub fn strict(from: P, to: P, slope: Slope) -> Result<Self, LineError> {
+        let ((a0, b0), (a1, b1)) = (from.coords(), to.coords());
+        let (da, db) = (a0.abs_diff(a1), b0.abs_diff(b1));
+        match slope {
+            Slope::Straight if da != 0 && db != 0             => Err(LineError::NotStraight(da, db)),
+            Slope::Diagonal if da != 0 && db != 0 && da != db => Err(LineError::NotDiagonal(da, db)),
+            _                                                 => Ok(Self::bresenham(from, to)),
+        }
+    }
+}
+
+impl<P: LinePoint> Iterator for LineIter<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let next = P::from_coords(self.next.0, self.next.1);
+        let err = 2 * self.err;
+        if err >= -self.delta.0 {
+            self.err -= self.delta.0;
+            self.next.1 += self.step.1;
+        }
+        if err <= self.delta.1 {
+            self.err += self.delta.1;
+            self.next.0 += self.step.0;
+        }
+        self.count -= 1;
+
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+}
+
+impl<P: LinePoint> ExactSizeIterator for LineIter<P> {}
+
+#[cfg(test)]
+mod test {
+    use super::{LineError, LineIter, Slope};
+    use crate::{grid::Cell, point::Point};
+
+    fn cells(from: (usize, usize), to: (usize, usize), slope: Slope) -> Result<Vec<(usize, usize)>, LineError> {
+        let line = LineIter::strict(Cell { row: from.0, col: from.1 }, Cell { row: to.0, col: to.1 }, slope)?;
+        Ok(line.map(|cell| (cell.row, cell.col)).collect())
+    }
+
+    #[test]
+    fn straight() {
+        assert_eq!(cells((2, 1), (2, 3), Slope::Straight), Ok(vec![(2, 1), (2, 2), (2, 3)]));
+        assert_eq!(cells((2, 1), (0, 1), Slope::Straight), Ok(vec![(2, 1), (1, 1), (0, 1)]));
+        assert_eq!(cells((1, 1), (1, 1), Slope::Straight), Ok(vec![(1, 1)]));
+        assert_eq!(cells((0, 0), (1, 1), Slope::Straight), Err(LineError::NotStraight(1, 1)));
+    }
+
+    #[test]
+    fn diagonal() {
+        assert_eq!(cells((0, 3), (2, 1), Slope::Diagonal), Ok(vec![(0, 3), (1, 2), (2, 1)]));
+        assert_eq!(cells((0, 0), (1, 2), Slope::Diagonal), Err(LineError::NotDiagonal(1, 2)));
+        assert!(LineIter::new(Cell { row: 0, col: 0 }, Cell { row: 1, col: 2 }).is_none());
+    }
+
+    #[test]
+    fn bresenham() {
+        assert_eq!(cells((0, 0), (2, 5), Slope::Any), Ok(vec![(0, 0), (0, 1), (1, 2), (1, 3), (2, 4), (2, 5)]));
+        assert_eq!(cells((5, 0), (0, 2), Slope::Any), Ok(vec![(5, 0), (4, 0), (3, 1), (2, 1), (1, 2), (0, 2)]));
+
+        let points: Vec<_> = LineIter::bresenham(Point([-2, 1]), Point([1, -1])).collect();
+        assert_eq!(points, vec![Point([-2, 1]), Point([-1, 0]), Point([0, 0]), Point([1, -1])]);
+    }
+}
@@ -0,0 +1,83 @@
+use super::{Cell, Dir, Grid, LineIter};
+
+/// The cells from a cell in a direction up to the edge of the grid, see [Grid::ray].
+#[derive(Clone,Debug)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    next: Option<Cell>,
+    dir: Dir,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Cell, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.next?;
+        self.next = cell.step(self.dir).filter(|&next| self.grid.contains(next));
+        Some((cell, &self.grid[cell]))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Iterates the cells in the direction, starting next to the cell (which is excluded) up to the edge.
+    /// Rays stop at the edge, also in toroidal grids.
+    pub fn ray(&self, cell: Cell, dir: Dir) -> Ray<'_, T> {
+        Ray { grid: self, next: cell.step(dir).filter(|&next| self.contains(next)), dir }
+    }
+
+    /// The first cell in the direction matching the predicate, see [Grid::ray].
+    pub fn first_in_dir(&self, cell: Cell, dir: Dir, pred: impl Fn(&T) -> bool) -> Option<(Cell, &T)> {
+        self.ray(cell, dir).find(|(_, value)| pred(value))
+    }
+
+    /// Counts the cells in the direction up to and including the first blocked one, eg: the trees that can be seen.
+    pub fn count_until_blocked(&self, cell: Cell, dir: Dir, blocked: impl Fn(&T) -> bool) -> usize {
+        let mut count = 0;
+        for (_, value) in self.ray(cell, dir) {
+            count += 1;
+            if blocked(value) {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Whether none of the cells strictly between both cells is blocked, along the line drawn by [LineIter::bresenham].
+    pub fn line_of_sight(&self, from: Cell, to: Cell, blocked: impl Fn(&T) -> bool) -> bool {
+        LineIter::bresenham(from, to)
+            .filter(|&cell| cell != from && cell != to)
+            .all(|cell| !blocked(&self[cell]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::{Cell, Dir, Grid, test::chars};
+
+    #[test]
+    fn ray() {
+        // 0 1 2
+        // 3 4 5
+        // 6 7 8
+        let grid = Grid::from_data(3, 3, (0..9).collect::<Vec<_>>());
+        let center = Cell { row: 1, col: 1 };
+        let values = |dir| grid.ray(Cell { row: 2, col: 0 }, dir).map(|(_, &v)| v).collect::<Vec<_>>();
+        assert_eq!(values(Dir::Up), vec![3, 0]);
+        assert_eq!(values(Dir::UpRight), vec![4, 2]);
+        assert_eq!(values(Dir::Left), vec![]);
+        assert_eq!(Dir::all().map(|dir| grid.ray(center, dir).count()).sum::<usize>(), 8);
+
+        assert_eq!(grid.first_in_dir(Cell { row: 0, col: 0 }, Dir::Right, |&v| v > 1), Some((Cell { row: 0, col: 2 }, &2)));
+        assert_eq!(grid.count_until_blocked(Cell { row: 0, col: 1 }, Dir::Down, |&v| v == 4), 1);
+        assert_eq!(grid.count_until_blocked(Cell { row: 0, col: 1 }, Dir::Down, |_| false), 2);
+    }
+
+    #[test]
+    fn line_of_sight() {
+        let grid = chars(&["....", ".#..", "...."]);
+        let blocked = |&c: &char| c == '#';
+        assert!(!grid.line_of_sight(Cell { row: 0, col: 0 }, Cell { row: 2, col: 2 }, blocked));
+        assert!(grid.line_of_sight(Cell { row: 0, col: 0 }, Cell { row: 1, col: 3 }, blocked));
+        assert!(grid.line_of_sight(Cell { row: 1, col: 1 }, Cell { row: 1, col: 3 }, blocked));
+    }
+}
@@ -0,0 +1,166 @@
+use super::{Cell, Grid};
+
+/// Which cells count as connected: only horizontal and vertical neighbors, or diagonal ones too.
+/// Cells of a [super::Topology::Hex] grid always connect to their six neighbors, whichever is given.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    fn diag(self) -> bool {
+        self == Connectivity::Eight
+    }
+}
+
+/// The connected components of a grid, see [Grid::components].
+/// Components are numbered from 0 in the order their first cell appears in the grid (row by row).
+#[derive(Clone,Debug)]
+pub struct Components {
+    /// The component id of every cell.
+    pub ids: Grid<usize>,
+    /// The number of cells of every component, by id.
+    pub sizes: Vec<usize>,
+    firsts: Vec<Cell>,
+}
+
+impl Components {
+    /// The number of components.
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    pub fn id(&self, cell: Cell) -> usize {
+        self.ids[cell]
+    }
+
+    /// The size of the component containing the cell.
+    pub fn size_of(&self, cell: Cell) -> usize {
+        self.sizes[self.ids[cell]]
+    }
+
+    /// The first cell of every component by id, eg: to look up the value the component consists of.
+    pub fn firsts(&self) -> &[Cell] {
+        &self.firsts
+    }
+}
+
+impl<T> Grid<T> {
+    /// Marks the cells reachable from the start cells by only entering cells matching the predicate.
+    /// Start cells not matching the predicate are ignored. The result has the same topology as the grid.
+    pub fn flood_fill(&self, starts: impl IntoIterator<Item=Cell>, conn: Connectivity, pred: impl Fn(&T) -> bool) -> Grid<bool> {
+        let mut filled = Grid::new(self.rows, self.cols, false).with_topology(self.topology);
+        let starts: Vec<Cell> = starts.into_iter().filter(|&cell| pred(&self[cell])).collect();
+        self.fill(&mut filled, starts, conn, |_, to| pred(to));
+        filled
+    }
+
+    /// Labels the connected components, where neighboring cells belong to the same component if `same` holds
+    /// for their values, eg: `PartialEq::eq` to group cells of equal value.
+    pub fn components(&self, conn: Connectivity, same: impl Fn(&T, &T) -> bool) -> Components {
+        let mut seen = Grid::new(self.rows, self.cols, false);
+        let mut ids = Grid::new(self.rows, self.cols, 0).with_topology(self.topology);
+        let mut sizes = Vec::new();
+        let mut firsts = Vec::new();
+        for cell in self.iter_cells() {
+            if seen[cell] {
+                continue;
+            }
+            let component = self.fill(&mut seen, vec![cell], conn, &same);
+            for &member in &component {
+                ids[member] = sizes.len();
+            }
+            sizes.push(component.len());
+            firsts.push(cell);
+        }
+        Components { ids, sizes, firsts }
+    }
+
+    /// Marks the open cells that are connected to the edge of the grid through other open cells.
+    pub fn exterior(&self, conn: Connectivity, open: impl Fn(&T) -> bool) -> Grid<bool> {
+        let edge = self.iter_cells().filter(|cell| {
+            cell.row == 0 || cell.col == 0 || cell.row == self.rows - 1 || cell.col == self.cols - 1
+        });
+        self.flood_fill(edge, conn, open)
+    }
+
+    /// Marks the open cells that are enclosed: not connected to the edge of the grid, see [Grid::exterior].
+    pub fn interior(&self, conn: Connectivity, open: impl Fn(&T) -> bool) -> Grid<bool> {
+        let exterior = self.exterior(conn, &open);
+        self.map_with_cell(|cell, value| open(value) && !exterior[cell])
+    }
+
+    /// Marks and returns all unmarked cells reachable from the start cells, stepping from one cell to
+    /// a neighbor when `can_step` holds for their values.
+    fn fill(&self, marked: &mut Grid<bool>, starts: Vec<Cell>, conn: Connectivity, can_step: impl Fn(&T, &T) -> bool) -> Vec<Cell> {
+        let mut filled = Vec::new();
+        let mut stack = Vec::new();
+        for start in starts {
+            if !std::mem::replace(&mut marked[start], true) {
+                stack.push(start);
+            }
+        }
+
+        while let Some(cell) = stack.pop() {
+            filled.push(cell);
+            for next in self.neighbors(cell.row, cell.col, conn.diag()) {
+                if !marked[next] && can_step(&self[cell], &self[next]) {
+                    marked[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        filled
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Connectivity::{Eight, Four};
+    use crate::grid::{Cell, Grid, test::chars};
+
+    #[test]
+    fn flood_fill() {
+        let grid = chars(&["..#.", "##..", "...#"]);
+        let filled = grid.flood_fill([Cell { row: 0, col: 0 }], Four, |&c| c == '.');
+        assert_eq!(filled.data().filter(|&&f| f).count(), 2);
+        let filled = grid.flood_fill([Cell { row: 0, col: 0 }], Eight, |&c| c == '.');
+        assert_eq!(filled.data().filter(|&&f| f).count(), 8);
+        let filled = grid.flood_fill([Cell { row: 0, col: 3 }], Four, |&c| c == '.');
+        assert_eq!(filled.data().filter(|&&f| f).count(), 6);
+        assert!(grid.flood_fill([Cell { row: 0, col: 2 }], Four, |&c| c == '.').data().all(|&f| !f));
+    }
+
+    #[test]
+    fn components() {
+        let grid = chars(&["#..#", ".#..", "...#"]);
+        let islands = |components: &super::Components| components.firsts().iter().filter(|&&cell| grid[cell] == '#').count();
+
+        let components = grid.components(Four, PartialEq::eq);
+        assert_eq!((components.len(), islands(&components)), (5, 4));
+        assert_eq!(components.ids, Grid::from_data(3, 4, vec![0, 1, 1, 2, 1, 3, 1, 1, 1, 1, 1, 4]));
+        assert_eq!(components.size_of(Cell { row: 0, col: 1 }), 8);
+
+        let components = grid.components(Eight, PartialEq::eq);
+        assert_eq!((components.len(), islands(&components)), (4, 3));
+        assert_eq!(components.sizes, vec![2, 8, 1, 1]);
+    }
+
+    #[test]
+    fn enclosed() {
+        let grid = chars(&["#####", "#..##", "##.#.", "#####"]);
+        let interior = grid.interior(Four, |&c| c == '.');
+        assert_eq!(interior.data().filter(|&&i| i).count(), 3);
+        assert!(grid.exterior(Four, |&c| c == '.')[Cell { row: 2, col: 4 }]);
+
+        // diagonally the ring is not closed
+        let grid = chars(&["#####", "#..#.", "###.#"]);
+        assert_eq!(grid.interior(Four, |&c| c == '.').data().filter(|&&i| i).count(), 2);
+        assert_eq!(grid.interior(Eight, |&c| c == '.').data().filter(|&&i| i).count(), 0);
+    }
+}
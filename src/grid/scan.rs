@@ -0,0 +1,118 @@
+use std::ops::{Range, Sub};
+
+use num::Zero;
+
+use super::Grid;
+
+/// A summed-area table: the sums of all rectangles starting at the top-left, see [Grid::prefix_sums].
+#[derive(Clone,Debug)]
+pub struct PrefixSums<T> {
+    /// The sum of the cells before row r and column c at (r, c), with an extra row and column of zeroes in front.
+    sums: Grid<T>,
+}
+
+/// The order of a scan: left to right and top to bottom, or the reverse.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Order {
+    Forward,
+    Backward,
+}
+
+impl<T: Copy + Zero + Sub<Output=T>> PrefixSums<T> {
+    /// The sum of the cells within the rows and columns, in constant time.
+    pub fn sum(&self, rows: Range<usize>, cols: Range<usize>) -> T {
+        assert!(rows.end < self.sums.rows && cols.end < self.sums.cols, "rectangle {:?}x{:?} exceeds grid of {}x{}",
+            rows, cols, self.sums.rows - 1, self.sums.cols - 1);
+        if rows.is_empty() || cols.is_empty() {
+            return T::zero();
+        }
+        // added first, so unsigned sums do not underflow
+        *self.sums.get(rows.end, cols.end) + *self.sums.get(rows.start, cols.start)
+            - *self.sums.get(rows.start, cols.end)
+            - *self.sums.get(rows.end, cols.start)
+    }
+
+    /// The sum of all cells.
+    pub fn total(&self) -> T {
+        *self.sums.get(self.sums.rows - 1, self.sums.cols - 1)
+    }
+}
+
+impl<T: Copy + Zero + Sub<Output=T>> Grid<T> {
+    /// Builds the summed-area table of the grid, for summing rectangles in constant time.
+    pub fn prefix_sums(&self) -> PrefixSums<T> {
+        let mut sums = Grid::new(self.rows + 1, self.cols + 1, T::zero());
+        for cell in self.iter_cells() {
+            let (row, col) = (cell.row + 1, cell.col + 1);
+            let sum = self[cell] + *sums.get(row - 1, col) + *sums.get(row, col - 1) - *sums.get(row - 1, col - 1);
+            *sums.get_mut(row, col) = sum;
+        }
+        PrefixSums { sums: sums.with_topology(self.topology) }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Folds every row in the order (left to right or backward), each cell gets the fold of all cells up to and including it,
+    /// eg: the highest tree so far when looking from the left with `scan_rows(Order::Forward, 0, |a, b| *a.max(b))`.
+    pub fn scan_rows<A: Clone>(&self, order: Order, init: A, f: impl Fn(&A, &T) -> A) -> Grid<A> {
+        let reverse = order == Order::Backward;
+        let mut data = Vec::with_capacity(self.data.len());
+        for row in 0..self.rows {
+            let start = data.len();
+            let mut acc = init.clone();
+            for i in 0..self.cols {
+                let col = if reverse { self.cols - 1 - i } else { i };
+                acc = f(&acc, self.get(row, col));
+                data.push(acc.clone());
+            }
+            if reverse {
+                data[start..].reverse();
+            }
+        }
+        Grid::from_data(self.rows, self.cols, data).with_topology(self.topology)
+    }
+
+    /// Folds every column in the order (top to bottom or backward), see [Grid::scan_rows].
+    pub fn scan_cols<A: Clone>(&self, order: Order, init: A, f: impl Fn(&A, &T) -> A) -> Grid<A> {
+        let reverse = order == Order::Backward;
+        let mut rows = Vec::with_capacity(self.rows);
+        let mut acc = vec![init; self.cols];
+        for i in 0..self.rows {
+            let row = if reverse { self.rows - 1 - i } else { i };
+            acc = acc.iter().zip(self.row(row)).map(|(acc, item)| f(acc, item)).collect();
+            rows.push(acc.clone());
+        }
+        if reverse {
+            rows.reverse();
+        }
+        Grid::from_data(self.rows, self.cols, rows.into_iter().flatten().collect()).with_topology(self.topology)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Order::{Backward, Forward};
+    use crate::grid::Grid;
+
+    #[test]
+    fn prefix_sums() {
+        let grid = Grid::parse_digits(["3142", "0578", "6209"].into_iter(), 1).unwrap();
+        let sums = grid.prefix_sums();
+        assert_eq!(sums.total(), 47);
+        assert_eq!(sums.sum(0..2, 1..3), 17);
+        assert_eq!(sums.sum(1..3, 0..1), 6);
+        assert_eq!(sums.sum(0..3, 3..4), 19);
+        assert_eq!(sums.sum(2..2, 0..4), 0);
+    }
+
+    #[test]
+    fn scan() {
+        // the highest tree so far
+        let heights = Grid::parse_digits(["30373", "25512"].into_iter(), 1).unwrap();
+        let highest = |a: &u32, b: &u32| *a.max(b);
+        assert_eq!(heights.scan_rows(Forward, 0, highest), Grid::from_data(2, 5, vec![3, 3, 3, 7, 7, 2, 5, 5, 5, 5]));
+        assert_eq!(heights.scan_rows(Backward, 0, highest), Grid::from_data(2, 5, vec![7, 7, 7, 7, 3, 5, 5, 5, 2, 2]));
+        assert_eq!(heights.scan_cols(Forward, 0, highest), Grid::from_data(2, 5, vec![3, 0, 3, 7, 3, 3, 5, 5, 7, 3]));
+        assert_eq!(heights.scan_cols(Backward, 4, highest), Grid::from_data(2, 5, vec![4, 5, 5, 7, 4, 4, 5, 5, 4, 4]));
+    }
+}
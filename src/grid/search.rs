@@ -0,0 +1,215 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, VecDeque}};
+
+use num::Zero;
+
+use super::{Cell, Dir, Grid};
+use crate::render::{Canvas, Glyph, Renderer};
+
+/// The result of a search on a grid: the distance to every reached cell and the shortest path leading there.
+/// Paths start at one of the start cells of the search.
+#[derive(Clone,Debug)]
+pub struct Paths<C> {
+    dist: Grid<Option<C>>,
+    prev: Grid<Option<Cell>>,
+}
+
+impl<C: Copy> Paths<C> {
+    fn new(rows: usize, cols: usize) -> Self {
+        Paths { dist: Grid::new(rows, cols, None), prev: Grid::new(rows, cols, None) }
+    }
+
+    /// The distance of the shortest path to the cell, None if it was not reached.
+    pub fn distance(&self, cell: Cell) -> Option<C> {
+        self.dist[cell]
+    }
+
+    /// The distances to all cells, None for cells that were not reached.
+    pub fn distances(&self) -> &Grid<Option<C>> {
+        &self.dist
+    }
+
+    /// Iterates the reached cells with their distance, in row-major order.
+    pub fn reached(&self) -> impl Iterator<Item=(Cell, C)> + '_ {
+        self.dist.iter_cells().filter_map(|cell| Some((cell, self.dist[cell]?)))
+    }
+
+    /// The shortest path from a start cell to the cell (both included), None if it was not reached.
+    pub fn path_to(&self, cell: Cell) -> Option<Vec<Cell>> {
+        self.dist[cell]?;
+        let mut path = vec![cell];
+        while let Some(prev) = self.prev[*path.last().unwrap()] {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Renders the grid with the path to the cell drawn on top using box characters, see [Paths::path_to].
+    /// The ends of the path keep their glyph, all cells of the path are highlighted.
+    pub fn render_path<T>(&self, grid: &Grid<T>, cell: Cell, renderer: Renderer, glyph: impl Fn(&T) -> Glyph) -> Option<String> {
+        let path = self.path_to(cell)?;
+        let mut lines = Grid::new(grid.rows, grid.cols, None);
+        for w in path.windows(3) {
+            lines[w[1]] = Some(box_char(Dir::between(&w[0], &w[1]), Dir::between(&w[1], &w[2])));
+        }
+
+        let renderer = renderer.highlight_cells(path.iter().map(|cell| (cell.row as isize, cell.col as isize)));
+        Some(renderer.cells(Canvas::bounds(grid)?, |row, col| match lines.get(row as usize, col as usize) {
+            Some(c) => (*c).into(),
+            None    => glyph(grid.get(row as usize, col as usize)),
+        }))
+    }
+}
+
+/// The box characters by the sides of the cell they connect.
+const BOX_CHARS: [(Dir, Dir, char); 6] = [
+    (Dir::Left, Dir::Right, '═'),
+    (Dir::Up,   Dir::Down,  '║'),
+    (Dir::Down, Dir::Right, '╔'),
+    (Dir::Left, Dir::Down,  '╗'),
+    (Dir::Up,   Dir::Right, '╚'),
+    (Dir::Left, Dir::Up,    '╝'),
+];
+
+/// The character connecting the step into a cell with the step out of it.
+fn box_char(into: Option<Dir>, out: Option<Dir>) -> char {
+    let (Some(into), Some(out)) = (into, out) else {
+        return '•';
+    };
+    let sides = [into.opposite(), out];
+    BOX_CHARS
+        .iter()
+        .find(|(a, b, _)| sides.contains(a) && sides.contains(b))
+        .map_or('•', |&(_, _, c)| c)
+}
+
+impl<T> Grid<T> {
+    /// Breadth-first search from all start cells at once, moving to the non-diagonal neighbors (see [Grid::neighbors])
+    /// for which `can_move(from, to)` holds. The distance is the number of steps.
+    pub fn bfs(&self, starts: impl IntoIterator<Item=Cell>, can_move: impl Fn(&T, &T) -> bool) -> Paths<usize> {
+        let mut paths = Paths::new(self.rows, self.cols);
+        let mut queue = VecDeque::new();
+        for start in starts {
+            if paths.dist[start].is_none() {
+                paths.dist[start] = Some(0);
+                queue.push_back(start);
+            }
+        }
+
+        while let Some(cell) = queue.pop_front() {
+            let dist = paths.dist[cell].unwrap();
+            for next in self.neighbors(cell.row, cell.col, false) {
+                if paths.dist[next].is_none() && can_move(&self[cell], &self[next]) {
+                    paths.dist[next] = Some(dist + 1);
+                    paths.prev[next] = Some(cell);
+                    queue.push_back(next);
+                }
+            }
+        }
+        paths
+    }
+
+    /// Dijkstra's search from all start cells at once, moving to the non-diagonal neighbors (see [Grid::neighbors])
+    /// at the cost returned by `cost(from, to)`, None if the move is not possible. Costs must not be negative.
+    pub fn dijkstra<C>(&self, starts: impl IntoIterator<Item=Cell>, cost: impl Fn(&T, &T) -> Option<C>) -> Paths<C>
+    where C: Copy + Ord + Zero
+    {
+        self.search(starts, None, cost, |_| C::zero())
+    }
+
+    /// A* search for the shortest path from any of the start cells to the goal, see [Grid::dijkstra].
+    /// The heuristic estimates the remaining cost from a cell to the goal, it must never overestimate
+    /// and not drop by more than the cost of a step, eg: the manhattan distance for moves costing at least 1.
+    /// The search stops at the goal, so only the cells on the way have their final distance.
+    pub fn astar<C>(&self, starts: impl IntoIterator<Item=Cell>, goal: Cell, cost: impl Fn(&T, &T) -> Option<C>, heuristic: impl Fn(Cell) -> C) -> Paths<C>
+    where C: Copy + Ord + Zero
+    {
+        self.search(starts, Some(goal), cost, heuristic)
+    }
+
+    fn search<C>(&self, starts: impl IntoIterator<Item=Cell>, goal: Option<Cell>, cost: impl Fn(&T, &T) -> Option<C>, heuristic: impl Fn(Cell) -> C) -> Paths<C>
+    where C: Copy + Ord + Zero
+    {
+        let mut paths = Paths::new(self.rows, self.cols);
+        let mut done = Grid::new(self.rows, self.cols, false);
+        let mut queue = BinaryHeap::new();
+        for start in starts {
+            paths.dist[start] = Some(C::zero());
+            queue.push(Reverse((heuristic(start), start.row, start.col)));
+        }
+
+        while let Some(Reverse((_, row, col))) = queue.pop() {
+            let cell = Cell { row, col };
+            if std::mem::replace(&mut done[cell], true) {
+                continue;
+            }
+            if goal == Some(cell) {
+                break;
+            }
+
+            let dist = paths.dist[cell].unwrap();
+            for next in self.neighbors(row, col, false) {
+                let Some(step) = cost(&self[cell], &self[next]) else {
+                    continue;
+                };
+                let next_dist = dist + step;
+                if !done[next] && paths.dist[next].is_none_or(|old| next_dist < old) {
+                    paths.dist[next] = Some(next_dist);
+                    paths.prev[next] = Some(cell);
+                    queue.push(Reverse((next_dist + heuristic(next), next.row, next.col)));
+                }
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+    use crate::{grid::{Cell, test::chars}, render::Renderer};
+
+    const WALL: char = '#';
+    const MAZE: [&str; 3] = ["....", ".##.", "...#"];
+
+    fn open(_: &char, to: &char) -> bool {
+        *to != WALL
+    }
+
+    #[test]
+    fn bfs() {
+        let grid = chars(&MAZE);
+        let paths = grid.bfs([Cell { row: 0, col: 0 }], open);
+        assert_eq!(paths.distance(Cell { row: 1, col: 3 }), Some(4));
+        assert_eq!(paths.distance(Cell { row: 2, col: 3 }), None);
+        assert_eq!(paths.path_to(Cell { row: 2, col: 2 }).unwrap().len(), 5);
+        assert_eq!(paths.reached().count(), 9);
+
+        // multiple starts: the nearest one wins
+        let paths = grid.bfs([Cell { row: 0, col: 0 }, Cell { row: 2, col: 2 }], open);
+        assert_eq!(paths.distance(Cell { row: 1, col: 3 }), Some(4));
+        assert_eq!(paths.path_to(Cell { row: 2, col: 1 }).unwrap()[0], Cell { row: 2, col: 2 });
+    }
+
+    #[test]
+    fn weighted() {
+        let grid = Grid::from_data(2, 3, vec![1, 9, 1, 1, 1, 1]);
+        let cost = |_: &u32, to: &u32| Some(*to);
+        let (start, goal) = (Cell { row: 0, col: 0 }, Cell { row: 0, col: 2 });
+        let paths = grid.dijkstra([start], cost);
+        assert_eq!(paths.distance(goal), Some(4));
+        assert_eq!(paths.path_to(goal).unwrap().len(), 5);
+
+        let manhattan = |cell: Cell| (cell.row.abs_diff(goal.row) + cell.col.abs_diff(goal.col)) as u32;
+        assert_eq!(grid.astar([start], goal, cost, manhattan).distance(goal), Some(4));
+    }
+
+    #[test]
+    fn render() {
+        let grid = chars(&MAZE);
+        let paths = grid.bfs([Cell { row: 2, col: 0 }], open);
+        let text = paths.render_path(&grid, Cell { row: 0, col: 3 }, Renderer::new(), |&c| c.into()).unwrap();
+        assert_eq!(text, "╔══.\n║##.\n...#\n");
+        assert!(paths.render_path(&grid, Cell { row: 2, col: 3 }, Renderer::new(), |&c| c.into()).is_none());
+    }
+}
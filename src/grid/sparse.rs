@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use super::Grid;
+
+/// The smallest rectangle containing a set of cells, given by signed coordinates.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub struct Bounds {
+    pub min_row: isize,
+    pub max_row: isize,
+    pub min_col: isize,
+    pub max_col: isize,
+}
+
+impl Bounds {
+    /// The bounds of the single cell.
+    pub fn of(row: isize, col: isize) -> Self {
+        Bounds { min_row: row, max_row: row, min_col: col, max_col: col }
+    }
+
+    /// Grows the bounds to contain the cell.
+    pub fn extend(self, row: isize, col: isize) -> Self {
+        Bounds {
+            min_row: self.min_row.min(row),
+            max_row: self.max_row.max(row),
+            min_col: self.min_col.min(col),
+            max_col: self.max_col.max(col),
+        }
+    }
+
+    pub fn rows(&self) -> RangeInclusive<isize> {
+        self.min_row..=self.max_row
+    }
+
+    pub fn cols(&self) -> RangeInclusive<isize> {
+        self.min_col..=self.max_col
+    }
+
+    pub fn height(&self) -> usize {
+        self.min_row.abs_diff(self.max_row) + 1
+    }
+
+    pub fn width(&self) -> usize {
+        self.min_col.abs_diff(self.max_col) + 1
+    }
+
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+        self.rows().contains(&row) && self.cols().contains(&col)
+    }
+
+    /// The overlapping part of both bounds, None if they do not overlap.
+    pub fn intersect(&self, other: &Bounds) -> Option<Bounds> {
+        let bounds = Bounds {
+            min_row: self.min_row.max(other.min_row),
+            max_row: self.max_row.min(other.max_row),
+            min_col: self.min_col.max(other.min_col),
+            max_col: self.max_col.min(other.max_col),
+        };
+        if bounds.min_row <= bounds.max_row && bounds.min_col <= bounds.max_col { Some(bounds) } else { None }
+    }
+}
+
+/// A grid without fixed size, eg: for coordinates that can grow in any direction.
+///
+/// Only the cells that were set are stored, all others read as the default value.
+/// Coordinates are signed (row, col) pairs, the bounding box of all cells ever set is tracked.
+#[derive(Clone,Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+    default: T,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid { cells: HashMap::new(), default, bounds: None }
+    }
+
+    /// The value of the cell, or the default value if it was not set.
+    pub fn get(&self, row: isize, col: isize) -> &T {
+        self.cells.get(&(row, col)).unwrap_or(&self.default)
+    }
+
+    pub fn is_set(&self, row: isize, col: isize) -> bool {
+        self.cells.contains_key(&(row, col))
+    }
+
+    pub fn set(&mut self, row: isize, col: isize, value: T) {
+        self.include(row, col);
+        self.cells.insert((row, col), value);
+    }
+
+    /// Removes the cell, which will read as default again. The bounds are not shrunk.
+    pub fn remove(&mut self, row: isize, col: isize) -> Option<T> {
+        self.cells.remove(&(row, col))
+    }
+
+    /// Grows the bounds to include the cell without setting it, eg: to render the origin.
+    pub fn include(&mut self, row: isize, col: isize) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.extend(row, col),
+            None         => Bounds::of(row, col),
+        });
+    }
+
+    /// The bounding box of all cells set so far, None if nothing was set yet.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// The number of cells that were set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterates the cells that were set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item=((isize, isize), &T)> {
+        self.cells.iter().map(|(cell, value)| (*cell, value))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Mutable access to the cell, setting it to the default value first if it was not set.
+    pub fn get_mut(&mut self, row: isize, col: isize) -> &mut T {
+        self.include(row, col);
+        self.cells.entry((row, col)).or_insert_with(|| self.default.clone())
+    }
+
+    /// Collects the cells within the bounds into a dense grid, where cell (0, 0) is the top-left of the bounds.
+    pub fn densify(&self, bounds: Bounds) -> Grid<T> {
+        let data = bounds
+            .rows()
+            .flat_map(|row| bounds.cols().map(move |col| (row, col)))
+            .map(|(row, col)| self.get(row, col).clone())
+            .collect();
+        Grid::from_data(bounds.height(), bounds.width(), data)
+    }
+
+    /// Collects all cells into a dense grid, see [SparseGrid::densify]. Gives an empty grid if nothing was set.
+    pub fn to_grid(&self) -> Grid<T> {
+        match self.bounds {
+            Some(bounds) => self.densify(bounds),
+            None         => Grid::from_data(0, 0, Vec::new()),
+        }
+    }
+
+    /// Stores the cells of the grid that differ from the default value, the top-left of the grid is at (0, 0).
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self where T: PartialEq {
+        let mut sparse = SparseGrid::new(default);
+        for cell in grid.iter_cells() {
+            let value = grid[cell].clone();
+            if value != sparse.default {
+                sparse.set(cell.row as isize, cell.col as isize, value);
+            }
+        }
+        sparse
+    }
+}
+
+/// Renders like [Grid], covering the bounds.
+impl<T: Clone + Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_grid())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bounds, SparseGrid};
+    use crate::grid::Grid;
+
+    #[test]
+    fn sparse() {
+        let mut grid = SparseGrid::new('.');
+        grid.set(-1, 2, '#');
+        *grid.get_mut(1, -1) = '~';
+        assert_eq!((grid.len(), *grid.get(-1, 2), *grid.get(0, 0)), (2, '#', '.'));
+        assert_eq!(grid.bounds(), Some(Bounds { min_row: -1, max_row: 1, min_col: -1, max_col: 2 }));
+        assert_eq!(grid.to_string(), "...#\n....\n~...\n");
+
+        let dense = grid.to_grid();
+        assert_eq!(SparseGrid::from_grid(&dense, '.').iter().count(), 2);
+    }
+
+    #[test]
+    fn empty() {
+        let grid = SparseGrid::new(0);
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_grid(), Grid::from_data(0, 0, vec![]));
+        assert_eq!(grid.to_string(), "");
+    }
+}
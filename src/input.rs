@@ -0,0 +1,497 @@
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::ParseError;
+
+/// The contents of an input-file, loaded once into a single buffer.
+/// 
+/// Lines are handed out as slices of that buffer (without their '\n'), while keeping track of the
+/// line-number so parse-errors can point at the offending line. Carriage returns are kept, unless the
+/// line-endings were normalized, see [Input::normalize].
+#[derive(Clone)]
+pub struct Input {
+    text: String,
+    pos: usize,
+    line: usize,
+}
+
+/// Iterator over the remaining lines of an [Input], advancing the input as lines are taken.
+pub struct Lines<'a> {
+    rest: &'a str,
+    pos: &'a mut usize,
+    line: &'a mut usize,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (line, rest) = self.rest.split_once('\n').unwrap_or((self.rest, ""));
+        *self.pos += self.rest.len() - rest.len();
+        *self.line += 1;
+        self.rest = rest;
+        Some(line)
+    }
+}
+
+/// The normalizations applied to an input, to make it independent of the machine it was saved on.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub struct Normalize {
+    /// Converts CRLF line-endings to LF.
+    pub line_endings: bool,
+    /// Strips a leading UTF-8 byte order mark.
+    pub bom: bool,
+    /// Trims trailing whitespace from each line.
+    pub trailing_whitespace: bool,
+}
+
+impl Normalize {
+    pub const NONE: Normalize = Normalize { line_endings: false, bom: false, trailing_whitespace: false };
+    pub const ALL: Normalize = Normalize { line_endings: true, bom: true, trailing_whitespace: true };
+}
+
+impl Default for Normalize {
+    /// Normalizes line-endings and the byte order mark, trailing whitespace can be significant (eg: day 5).
+    fn default() -> Self {
+        Normalize { trailing_whitespace: false, ..Normalize::ALL }
+    }
+}
+
+/// A change made while normalizing an input.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Change {
+    Bom,
+    LineEndings { count: usize },
+    TrailingWhitespace { count: usize, first: usize },
+}
+
+/// A group of consecutive non-blank lines, as separated by blank lines in the input.
+#[derive(Clone,Debug)]
+pub struct Group<'a> {
+    lines: Vec<&'a str>,
+    first: usize,
+}
+
+impl<'a> Group<'a> {
+    pub fn lines(&self) -> &[&'a str] {
+        &self.lines
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// The (1-based) line-number in the input of the i-th line of the group.
+    pub fn line(&self, i: usize) -> usize {
+        self.first + i
+    }
+
+    /// Annotates the error with the i-th line of the group.
+    pub fn at_line(&self, i: usize, err: ParseError) -> ParseError {
+        err.at_line(self.line(i), self.lines[i])
+    }
+
+    /// Returns the lines of a group that should have exactly N lines.
+    pub fn expect_lines<const N: usize>(&self) -> Result<[&'a str; N], ParseError> {
+        self.lines.as_slice().try_into().map_err(|_| {
+            let err = ParseError::new(format!("expected a group of {} lines, got {}", N, self.len()));
+            // point at the first superfluous line, or at the last line when lines are missing
+            self.at_line(N.min(self.len() - 1), err)
+        })
+    }
+
+    /// Parses each line of the group with the given function,
+    /// annotating any error with the line it occurred on.
+    pub fn parse_lines<T,F>(&self, mut f: F) -> Result<Vec<T>, ParseError>
+    where F: FnMut(&'a str) -> Result<T, ParseError>
+    {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| f(line).map_err(|err| self.at_line(i, err)))
+            .collect()
+    }
+}
+
+/// Iterator over the remaining groups of an [Input], see [Input::groups].
+pub struct Groups<'a>(Lines<'a>);
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = Group<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.0.find(|line| !line.trim().is_empty())?;
+        let mut group = Group { lines: vec![first], first: *self.0.line };
+        group.lines.extend(self.0.by_ref().take_while(|line| !line.trim().is_empty()));
+        Some(group)
+    }
+}
+
+impl Input {
+    pub fn new(text: String) -> Self {
+        Input { text, pos: 0, line: 0 }
+    }
+
+    /// Input from an in-memory string, eg: an example embedded in a test.
+    pub fn from_text(text: &str) -> Self {
+        Input::new(text.to_owned())
+    }
+
+    /// Reads the whole input at once, errors are annotated with the given origin.
+    pub fn from_reader(mut reader: impl Read, origin: &Path) -> Result<Self, ParseError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|err| ParseError::new(err.to_string()).in_file(origin))?;
+
+        let text = String::from_utf8(bytes).map_err(|err| {
+            let valid = &err.as_bytes()[..err.utf8_error().valid_up_to()];
+            let line = valid.iter().filter(|&&b| b == b'\n').count() + 1;
+            ParseError { line: Some(line), ..ParseError::new("invalid UTF-8").in_file(origin) }
+        })?;
+        Ok(Input::new(text))
+    }
+
+    /// Reads all of stdin, until end-of-file.
+    pub fn from_stdin() -> Result<Self, ParseError> {
+        Input::from_reader(io::stdin().lock(), Source::Stdin.origin())
+    }
+
+    /// Normalizes the remaining text, returning the changes made.
+    /// The text is only copied when line-endings or trailing whitespace have to change.
+    pub fn normalize(&mut self, normalize: Normalize) -> Vec<Change> {
+        let mut changes = Vec::new();
+        if normalize.bom && self.rest().starts_with('\u{feff}') {
+            self.pos += '\u{feff}'.len_utf8();
+            changes.push(Change::Bom);
+        }
+
+        let rest = &self.text[self.pos..];
+        let has_crlf = normalize.line_endings && rest.contains("\r\n");
+        let has_trailing = normalize.trailing_whitespace && rest.lines().any(|line| line.trim_end().len() != line.len());
+        if !has_crlf && !has_trailing {
+            return changes;
+        }
+
+        let mut text = String::with_capacity(rest.len());
+        let mut crlf = 0;
+        let mut trimmed = None;
+        for (i, line) in rest.split_inclusive('\n').enumerate() {
+            let (mut content, mut ending) = match line.strip_suffix('\n') {
+                Some(content) => match content.strip_suffix('\r') {
+                    Some(content) => (content, "\r\n"),
+                    None          => (content, "\n"),
+                },
+                None => (line, ""),
+            };
+            if normalize.line_endings && ending == "\r\n" {
+                ending = "\n";
+                crlf += 1;
+            }
+            if normalize.trailing_whitespace && content.trim_end().len() != content.len() {
+                content = content.trim_end();
+                let (count, _) = trimmed.get_or_insert((0, self.line + i + 1));
+                *count += 1;
+            }
+            text.push_str(content);
+            text.push_str(ending);
+        }
+        if crlf > 0 {
+            changes.push(Change::LineEndings { count: crlf });
+        }
+        if let Some((count, first)) = trimmed {
+            changes.push(Change::TrailingWhitespace { count, first });
+        }
+
+        self.text.truncate(self.pos);
+        self.text.push_str(&text);
+        changes
+    }
+
+    /// The (1-based) line-number of the line last returned.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The remaining text, starting at the next line.
+    pub fn rest(&self) -> &str {
+        &self.text[self.pos..]
+    }
+
+    /// The remaining text as bytes, starting at the next line.
+    pub fn bytes(&self) -> &[u8] {
+        self.rest().as_bytes()
+    }
+
+    /// Returns the next line, if any.
+    pub fn next_line(&mut self) -> Option<&str> {
+        let Input { text, pos, line } = self;
+        Lines { rest: &text[*pos..], pos, line }.next()
+    }
+
+    /// Iterates the remaining lines. Lines not taken from the iterator remain in the input.
+    pub fn lines(&mut self) -> Lines<'_> {
+        let Input { text, pos, line } = self;
+        Lines { rest: &text[*pos..], pos, line }
+    }
+
+    /// Iterates the remaining lines as bytes, eg: for inputs that are known to be ASCII.
+    pub fn byte_lines(&mut self) -> impl Iterator<Item=&[u8]> {
+        self.lines().map(str::as_bytes)
+    }
+
+    /// Iterates the remaining groups of lines separated by blank lines, eg: the records of a list.
+    /// Any number of blank lines separates two groups, leading and trailing blank lines are ignored.
+    pub fn groups(&mut self) -> Groups<'_> {
+        Groups(self.lines())
+    }
+
+    /// Splits the remaining input into exactly N groups, eg: for inputs consisting of differently formatted parts.
+    pub fn sections<const N: usize>(&mut self) -> Result<[Group<'_>; N], ParseError> {
+        let sections: Vec<_> = self.groups().collect();
+        let len = sections.len();
+        sections.try_into().map_err(|sections: Vec<Group>| {
+            let err = ParseError::new(format!("expected {} sections separated by blank lines, got {}", N, len));
+            match sections.get(N) {
+                Some(section) => section.at_line(0, err),
+                None          => err,
+            }
+        })
+    }
+
+    /// Parses each remaining line with the given function,
+    /// annotating any error with the line it occurred on.
+    pub fn parse_lines<T,F>(mut self, mut f: F) -> Result<Vec<T>, ParseError>
+    where F: FnMut(&str) -> Result<T, ParseError>
+    {
+        let first = self.line + 1;
+        self.lines()
+            .enumerate()
+            .map(|(i, line)| f(line).map_err(|err| err.at_line(first + i, line)))
+            .collect()
+    }
+}
+
+pub fn read_lines(file: &Path) -> Result<Input, ParseError> {
+    let reader = File::open(file)
+        .map_err(|err| ParseError::new(err.to_string()).in_file(file))?;
+
+    Input::from_reader(reader, file)
+}
+
+/// Reads the file like [read_lines], normalizing it and warning on stderr about any changes made.
+pub fn read_lines_normalized(file: &Path, normalize: Normalize) -> Result<Input, ParseError> {
+    let mut input = read_lines(file)?;
+    warn_changes(file, &input.normalize(normalize));
+    Ok(input)
+}
+
+fn warn_changes(origin: &Path, changes: &[Change]) {
+    for change in changes {
+        eprintln!("warning: {}: {}", origin.display(), change);
+    }
+}
+
+/// Where the input of a run is read from.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// The source for a command-line argument, where "-" means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            _   => Source::File(PathBuf::from(arg)),
+        }
+    }
+
+    /// Reads and normalizes the input, warning on stderr about any changes made.
+    pub fn read(&self, normalize: Normalize) -> Result<Input, ParseError> {
+        let mut input = match self {
+            Source::File(path) => read_lines(path)?,
+            Source::Stdin      => Input::from_stdin()?,
+        };
+        warn_changes(self.origin(), &input.normalize(normalize));
+        Ok(input)
+    }
+
+    /// The path errors are annotated with, "<stdin>" for stdin.
+    pub fn origin(&self) -> &Path {
+        match self {
+            Source::File(path) => path,
+            Source::Stdin      => Path::new("<stdin>"),
+        }
+    }
+
+    /// The extension of the input, when it is a file named after the day, eg: Some(Some("test")) for "day1.test".
+    /// The puzzle input itself has no extension (Some(None)), other files and stdin give None.
+    pub fn ext(&self, day: u32) -> Option<Option<&str>> {
+        let Source::File(path) = self else {
+            return None;
+        };
+        let name = path.file_name()?.to_str()?;
+        let rest = name.strip_prefix("day")?;
+        let (number, ext) = match rest.split_once('.') {
+            Some((number, ext)) => (number, Some(ext)),
+            None                => (rest, None),
+        };
+        if number.parse() != Ok(day) || ext == Some("") {
+            return None;
+        }
+        Some(ext)
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.origin().display())
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Bom                                 => write!(f, "stripped byte order mark"),
+            Change::LineEndings { count }               => write!(f, "converted {} CRLF line-endings", count),
+            Change::TrailingWhitespace { count, first } => write!(f, "trimmed trailing whitespace of {} lines, first on line {}", count, first),
+        }
+    }
+}
+
+/// The default directory holding the input-files.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Returns the path of the input-file for the given day.
+/// Files are searched in the "inputs" directory and should be of the form "day1" for puzzle input,
+/// the optional extension selects an alternative input, eg: "day1.test" for the example input.
+pub fn get_input_file(day: u32, ext: Option<&str>) -> PathBuf {
+    get_input_file_in(Path::new(INPUT_DIR), day, ext)
+}
+
+/// Like [get_input_file], but searching the given directory.
+pub fn get_input_file_in(dir: &Path, day: u32, ext: Option<&str>) -> PathBuf {
+    let mut path = dir.join(format!("day{}", day));
+
+    if let Some(ext) = ext {
+        path.set_extension(ext);
+    }
+
+    path
+}
+
+/// Returns all input-files of the given day in the directory, ie: "day1" and any "day1.<ext>".
+/// The puzzle input comes first, followed by the others sorted on their extension.
+pub fn get_input_files_in(dir: &Path, day: u32) -> Result<Vec<PathBuf>, ParseError> {
+    let entries = fs::read_dir(dir).map_err(|err| ParseError::new(err.to_string()).in_file(dir))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|err| ParseError::new(err.to_string()).in_file(dir))?.path();
+        if !path.is_file() {
+            continue;
+        }
+        if let Some(ext) = Source::File(path.clone()).ext(day) {
+            files.push((ext.map(str::to_owned), path));
+        }
+    }
+    files.sort();
+
+    Ok(files.into_iter().map(|(_, path)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{Change, Input, Normalize, Source};
+
+    #[test]
+    fn lines() {
+        let mut input = Input::from_text("1\n2\n\n3\n");
+        assert_eq!(input.next_line(), Some("1"));
+        assert_eq!(input.lines().take_while(|line| !line.is_empty()).collect::<Vec<_>>(), vec!["2"]);
+        assert_eq!((input.line(), input.rest()), (3, "3\n"));
+        assert_eq!(input.byte_lines().collect::<Vec<_>>(), vec![b"3"]);
+        assert_eq!(input.next_line(), None);
+    }
+
+    #[test]
+    fn parse_lines() {
+        let mut input = Input::from_text("1\n2\n\n3");
+        input.next_line();
+        let err = input.parse_lines(|line| Ok(line.parse::<u32>()?)).unwrap_err();
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn groups() {
+        let mut input = Input::from_text("\n1\n2\n\n\n3\n \n4\n5\n6");
+        let groups: Vec<_> = input.groups().collect();
+        assert_eq!(groups.iter().map(|group| group.lines()).collect::<Vec<_>>(), vec![&["1", "2"][..], &["3"], &["4", "5", "6"]]);
+        assert_eq!(groups.iter().map(|group| group.line(0)).collect::<Vec<_>>(), vec![2, 6, 8]);
+
+        assert_eq!(groups[0].expect_lines().ok(), Some(["1", "2"]));
+        assert_eq!(groups[1].expect_lines::<2>().unwrap_err().line, Some(6));
+        assert_eq!(groups[2].expect_lines::<2>().unwrap_err().line, Some(10));
+    }
+
+    #[test]
+    fn sections() {
+        let mut input = Input::from_text("1\n\n2\n3\n");
+        let [a, b] = input.sections().unwrap();
+        assert_eq!((a.lines(), b.lines()), (&["1"][..], &["2", "3"][..]));
+
+        let mut input = Input::from_text("1\n\n2\n\n3\n");
+        let err = input.sections::<2>().unwrap_err();
+        assert_eq!((err.line, err.msg.as_str()), (Some(5), "expected 2 sections separated by blank lines, got 3"));
+    }
+
+    #[test]
+    fn normalize() {
+        let text = "\u{feff}1 \r\n2\r\n3\t\n";
+        let mut input = Input::from_text(text);
+        assert!(input.normalize(Normalize::NONE).is_empty());
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["\u{feff}1 \r", "2\r", "3\t"]);
+
+        let mut input = Input::from_text("\u{feff}1\n2\n");
+        assert_eq!(input.normalize(Normalize::ALL), vec![Change::Bom]);
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["1", "2"]);
+
+        let mut input = Input::from_text(text);
+        assert_eq!(input.normalize(Normalize::default()), vec![Change::Bom, Change::LineEndings { count: 2 }]);
+        assert_eq!(input.rest(), "1 \n2\n3\t\n");
+
+        let mut input = Input::from_text(text);
+        input.next_line();
+        assert_eq!(input.normalize(Normalize::ALL), vec![Change::LineEndings { count: 1 }, Change::TrailingWhitespace { count: 1, first: 3 }]);
+        assert_eq!(input.rest(), "2\n3\n");
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let err = Input::from_reader(&b"1\n2\n\xff"[..], Path::new("day1")).err().unwrap();
+        assert_eq!(err.to_string(), "day1:3: invalid UTF-8");
+    }
+
+    #[test]
+    fn ext() {
+        let file = |name: &str| Source::File(PathBuf::from("inputs").join(name));
+        assert_eq!(file("day1").ext(1), Some(None));
+        assert_eq!(file("day1.test").ext(1), Some(Some("test")));
+        assert_eq!(file("day10.test").ext(1), None);
+        assert_eq!(file("answers").ext(1), None);
+        assert_eq!(Source::Stdin.ext(1), None);
+    }
+}
@@ -1,143 +1,308 @@
-use std::env;
-use std::fmt::Debug;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use std::fmt::Display;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
+pub mod answers;
+pub mod bench;
+pub mod error;
 pub mod grid;
+pub mod input;
+pub mod options;
 pub mod point;
+pub mod render;
 
+pub use error::ParseError;
+pub use input::{get_input_file, read_lines, read_lines_normalized, Group, Input, Lines, Normalize, Source};
+pub use options::{Context, Opt, OptionError};
 
-pub struct Input(Lines<BufReader<File>>);
-
-impl Iterator for Input {
-    type Item = String;
+/// Splits the line on the first occurrence of the separator and parses both halves.
+pub fn parse_pair<A,B>(line: &str, sep: &str) -> Result<(A, B), ParseError>
+where A: FromStr,
+      B: FromStr,
+      <A as FromStr>::Err: Into<ParseError>,
+      <B as FromStr>::Err: Into<ParseError>,
+{
+    let (a, b) = line
+        .split_once(sep)
+        .ok_or_else(|| ParseError::new(format!("expected separator {:?}", sep)))?;
+    let a = a.parse().map_err(|err: A::Err| err.into().within(line, a))?;
+    let b = b.parse().map_err(|err: B::Err| err.into().within(line, b))?;
+    Ok((a, b))
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            match self.0.next() {
-                None => return None,
-                Some(Ok(x)) => return Some(x),
-                Some(Err(_)) => {},
-            }
-        }
-    }
+pub fn slice_mut_twice<T>(arr: &mut [T], a: usize, b: usize) -> (&mut T, &mut T) {
+    // could be written using split_at_mut, but would be more complicated in favor of not using unsafe directly
+    assert!(a != b);
+    unsafe {(
+        &mut *(&mut arr[a] as *mut _),
+        &mut *(&mut arr[b] as *mut _)
+    )}
 }
 
-pub fn get_input_file(ext: Option<&str>) -> PathBuf {
-    let exe = env::current_exe().unwrap();
+/// A puzzle-solution, split into parsing the input and solving both parts on the parsed result.
+/// Parsing is done once and its result is shared by both parts.
+/// 
+/// Days can declare their own command-line options (eg: to enable debug-output), the given values are
+/// passed to `parse` and both parts through the context.
+pub trait Solution {
+    const DAY: u32;
+    const OPTIONS: &'static [Opt] = &[];
 
-    // optionally strip the dynamic part of the executable that is added by the test-framework
-    let filename = exe.file_name().unwrap().to_string_lossy();
-    let filename = match filename.split_once('-') {
-        Some((x, _)) => x,
-        None => &filename,
-    };
+    type Parsed;
+    type Part1: Display;
+    type Part2: Display;
 
-    let mut path = PathBuf::from("inputs");
-    path.push(filename);
+    fn parse(input: Input, ctx: &Context) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed, ctx: &Context) -> Self::Part1;
+    fn part2(parsed: &Self::Parsed, ctx: &Context) -> Self::Part2;
+}
 
-    if let Some(ext) = ext {
-        path.set_extension(ext);
-    }
+#[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
 
-    path
+/// The answer of a single part together with the time it took to calculate it.
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
 }
 
-pub fn read_lines(file: PathBuf) -> Input {
-    Input(BufReader::new(File::open(file).unwrap()).lines())
+/// The result of running a solution: the time spent parsing and the answers of the requested parts.
+pub struct Answers {
+    pub parse: Duration,
+    pub parts: Vec<Answer>,
 }
 
-pub fn parse_pair<A,B>(line: &str, sep: &str) -> (A, B)
-where A: FromStr,
-      B: FromStr,
-      <A as FromStr>::Err: Debug,
-      <B as FromStr>::Err: Debug,
-{
-    let (a, b) = line.split_once(sep).unwrap();
-    (a.parse().unwrap(), b.parse().unwrap())
+/// Parses the input and runs the requested part, or both parts when `only` is None.
+pub fn solve<S: Solution>(input: Input, ctx: &Context, only: Option<Part>) -> Result<Answers, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input, ctx)?;
+    let parse = start.elapsed();
+
+    let mut parts = Vec::new();
+    if only != Some(Part::Two) {
+        let start = Instant::now();
+        let value = S::part1(&parsed, ctx).to_string();
+        parts.push(Answer { part: Part::One, value, elapsed: start.elapsed() });
+    }
+    if only != Some(Part::One) {
+        let start = Instant::now();
+        let value = S::part2(&parsed, ctx).to_string();
+        parts.push(Answer { part: Part::Two, value, elapsed: start.elapsed() });
+    }
+
+    Ok(Answers { parse, parts })
 }
 
-pub struct OwnedChars {
-    s: String,
-    i: usize,
+/// A single puzzle-solution as registered in the `aoc` binary.
+pub struct Day {
+    pub day: u32,
+    pub options: &'static [Opt],
+    pub solve: fn(Input, &Context, Option<Part>) -> Result<Answers, ParseError>,
 }
 
-impl Iterator for OwnedChars {
-    type Item = char;
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Day { day: S::DAY, options: S::OPTIONS, solve: solve::<S> }
+    }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let c = self.s[self.i..].chars().next()?;
+/// The options of the runner itself, available for all days.
+pub const RUNNER_OPTIONS: &[Opt] = &[
+    Opt::flag("test",  "use the example input, eg: inputs/day1.test"),
+    Opt::text("ext",   "use the input with the given extension, eg: test2"),
+    Opt::text("input", "read the input from the given file instead, or from stdin for '-'"),
+    Opt::text("dir",   "directory holding the input-files and answers (default inputs)"),
+    Opt::flag("batch", "run on every input-file of the day, eg: inputs/day1 and inputs/day1.test"),
+    Opt::flag("trim",  "also trim trailing whitespace of the input lines"),
+    Opt::flag("raw",   "read the input as-is, without normalizing line-endings and byte order mark"),
+    Opt::int("part",   "only run the given part (1 or 2)"),
+    Opt::flag("bench", "benchmark the solution instead of printing the answers"),
+    Opt::int("runs",   "number of benchmark runs (default 100)"),
+    Opt::flag("json",  "print benchmark results as JSON"),
+    Opt::flag("check", "check the answers against inputs/answers"),
+    Opt::flag("help",  "print this help"),
+];
 
-        self.i += c.len_utf8();
+/// The runner options, validated and extracted from the context.
+pub struct RunOptions {
+    pub ext: Option<String>,
+    pub input: Option<Source>,
+    pub dir: PathBuf,
+    pub batch: bool,
+    pub normalize: Normalize,
+    pub only: Option<Part>,
+    pub bench: Option<usize>,
+    pub json: bool,
+    pub check: bool,
+}
 
-        Some(c)
+impl RunOptions {
+    pub fn from_context(ctx: &Context) -> Result<Self, OptionError> {
+        let ext = match (ctx.flag("test"), ctx.text("ext")) {
+            (true, Some(_)) => return Err(OptionError("options '--test' and '--ext' are exclusive".into())),
+            (true, None)    => Some("test".to_owned()),
+            (false, ext)    => ext.map(str::to_owned),
+        };
+        let input = ctx.text("input").map(Source::from_arg);
+        let batch = ctx.flag("batch");
+        match (&ext, &input, batch) {
+            (Some(_), Some(_), _) => return Err(OptionError("option '--input' excludes '--test' and '--ext'".into())),
+            (Some(_), _, true)    => return Err(OptionError("option '--batch' excludes '--test' and '--ext'".into())),
+            (_, Some(_), true)    => return Err(OptionError("options '--input' and '--batch' are exclusive".into())),
+            _                     => (),
+        }
+        let dir = PathBuf::from(ctx.text("dir").unwrap_or(input::INPUT_DIR));
+        let normalize = match (ctx.flag("trim"), ctx.flag("raw")) {
+            (true, true)   => return Err(OptionError("options '--trim' and '--raw' are exclusive".into())),
+            (true, false)  => Normalize::ALL,
+            (false, true)  => Normalize::NONE,
+            (false, false) => Normalize::default(),
+        };
+        let only = match ctx.int("part") {
+            None    => None,
+            Some(1) => Some(Part::One),
+            Some(2) => Some(Part::Two),
+            Some(n) => return Err(OptionError(format!("there is no part {}", n))),
+        };
+        let bench = match (ctx.flag("bench"), ctx.int("runs")) {
+            (false, Some(_))         => return Err(OptionError("option '--runs' requires '--bench'".into())),
+            (false, None)            => None,
+            (true, None)             => Some(100),
+            (true, Some(n)) if n > 0 => Some(n as usize),
+            (true, Some(n))          => return Err(OptionError(format!("invalid number of runs {}", n))),
+        };
+        let (json, check) = (ctx.flag("json"), ctx.flag("check"));
+        match (&bench, json, check) {
+            (None, true, _)    => return Err(OptionError("option '--json' requires '--bench'".into())),
+            (Some(_), _, true) => return Err(OptionError("options '--bench' and '--check' are exclusive".into())),
+            _                  => (),
+        }
+
+        Ok(RunOptions { ext, input, dir, batch, normalize, only, bench, json, check })
     }
 }
 
-impl OwnedChars {
-    pub fn from_string(string: String) -> OwnedChars {
-        OwnedChars {
-            s: string,
-            i: 0,
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
         }
     }
 }
 
-pub fn slice_mut_twice<T>(arr: &mut [T], a: usize, b: usize) -> (&mut T, &mut T) {
-    // could be written using split_at_mut, but would be more complicated in favor of not using unsafe directly
-    assert!(a != b);
-    unsafe {(
-        &mut *(&mut arr[a] as *mut _),
-        &mut *(&mut arr[b] as *mut _)
-    )}
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // multi-line answers (eg: rendered letters) start on their own line
+        let sep = if self.value.contains('\n') { '\n' } else { ' ' };
+        write!(f, "{} ({:?}):{}{}", self.part, self.elapsed, sep, self.value)
+    }
 }
 
-/// Runs the provided function on the input from the file derived form the binary-name.
-/// Files are searched in the "inputs" directory and should be of the form "day1" for puzzle input.
+/// Runs the given day on its puzzle input and prints the answers with their timings.
 /// 
-/// When the arguments contain an argument that starts with "test", an example input-file will be chosen instead.
+/// The runner options select an alternative input (eg: "--test" for "inputs/day1.test", "--input -" for stdin
+/// or "--batch" for all input-files of the day), a single part, benchmarking over a number of runs (optionally
+/// printed as JSON) or checking the answers against the known answers (see [answers::KnownAnswers]).
+/// The context is passed to the day for its own options.
 /// 
-/// eg: if "test" is passed as an argument, the file "inputs/day1.test" will be used
-///     if "test2" is passed as an argument, the file "inputs/day1.test2" will be used
-pub fn run_and_print<F,R>(f: F)
-where
-    F: FnOnce(Input) -> R,
-    R: Debug
-{
-    let ext = env::args().find(|arg| arg.starts_with("test"));
-    println!("{:?}", f(read_lines(get_input_file(ext.as_deref()))));
+/// Returns false when a checked answer did not match its known answer, or when an input failed in batch-mode.
+/// Errors while reading or parsing the input are returned, annotated with the input-file.
+pub fn run_and_print(day: &Day, run: &RunOptions, ctx: &Context) -> Result<bool, ParseError> {
+    let known = if run.check {
+        Some(answers::KnownAnswers::load_from(&answers::get_answers_file_in(&run.dir))?)
+    } else {
+        None
+    };
+
+    if !run.batch {
+        let source = run.input
+            .clone()
+            .unwrap_or_else(|| Source::File(input::get_input_file_in(&run.dir, day.day, run.ext.as_deref())));
+        return run_source(day, &source, run, ctx, known.as_ref());
+    }
+
+    // report every input-file on its own, continuing after errors
+    let files = input::get_input_files_in(&run.dir, day.day)?;
+    if files.is_empty() {
+        return Err(ParseError::new(format!("no input-files found for day{}", day.day)).in_file(&run.dir));
+    }
+    let mut passed = true;
+    for path in files {
+        let source = Source::File(path);
+        println!("day{} input {}", day.day, source);
+        match run_source(day, &source, run, ctx, known.as_ref()) {
+            Ok(ok) => passed &= ok,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                passed = false;
+            },
+        }
+    }
+    Ok(passed)
+}
+
+fn run_source(day: &Day, source: &Source, run: &RunOptions, ctx: &Context, known: Option<&answers::KnownAnswers>) -> Result<bool, ParseError> {
+    let input = source.read(run.normalize)?;
+    if let Some(runs) = run.bench {
+        let bench = bench::bench(day, &input, ctx, run.only, runs).map_err(|err| err.in_file(source.origin()))?;
+        if run.json {
+            println!("{}", bench.to_json());
+        } else {
+            println!("{}", bench);
+        }
+        return Ok(true);
+    }
+
+    let answers = (day.solve)(input, ctx, run.only).map_err(|err| err.in_file(source.origin()))?;
+    println!("day{} parse ({:?})", day.day, answers.parse);
+    let mut passed = true;
+    for answer in &answers.parts {
+        println!("day{} {}", day.day, answer);
+        if let Some(known) = known {
+            // answers are only known for the files named after the day
+            let check = match source.ext(day.day) {
+                Some(ext) => known.check(day.day, ext, answer.part, &answer.value),
+                None      => answers::Check::Missing,
+            };
+            passed &= !matches!(check, answers::Check::Fail { .. });
+            println!("day{} {}: {}", day.day, answer.part, check);
+        }
+    }
+    Ok(passed)
 }
 
 pub mod test {
-    use crate::{get_input_file,read_lines,Input};
-
-    pub fn test_file<F,R>(ext: Option<&str>, f: F, expected: R) 
-    where
-        F: FnOnce(Input) -> R,
-        R: PartialEq,
-        R: std::fmt::Debug
-    {
-        let result = f(read_lines(get_input_file(ext)));
-        assert_eq!(result, expected);
+    use crate::{get_input_file, read_lines_normalized, Context, Normalize, Part, Solution};
+    use crate::answers::KnownAnswers;
+
+    /// Checks both parts of the solution against the known answers for the input-file with the given extension.
+    /// Each part is run and checked on its own, so a failure points out the offending part.
+    pub fn test_file<S: Solution>(ext: Option<&str>) {
+        let known = KnownAnswers::load().unwrap_or_else(|err| panic!("{}", err));
+        let expected = |part| known
+            .get(S::DAY, ext, part)
+            .unwrap_or_else(|| panic!("no known answer for {} of {}", part, get_input_file(S::DAY, ext).display()));
+
+        let path = get_input_file(S::DAY, ext);
+        let ctx = Context::default();
+        let parsed = read_lines_normalized(&path, Normalize::default())
+            .and_then(|input| S::parse(input, &ctx))
+            .unwrap_or_else(|err| panic!("{}", err.in_file(&path)));
+        assert_eq!(S::part1(&parsed, &ctx).to_string(), expected(Part::One), "part1 of day{}", S::DAY);
+        assert_eq!(S::part2(&parsed, &ctx).to_string(), expected(Part::Two), "part2 of day{}", S::DAY);
     }
 
-    pub fn test_example<F,R>(f: F, expected: R) 
-    where
-        F: FnOnce(Input) -> R,
-        R: PartialEq,
-        R: std::fmt::Debug
-    {
-        test_file(Some("test"), f, expected)
+    pub fn test_example<S: Solution>() {
+        test_file::<S>(Some("test"))
     }
 
-    pub fn test_puzzle<F,R>(f: F, expected: R) 
-    where
-        F: FnOnce(Input) -> R,
-        R: PartialEq,
-        R: std::fmt::Debug
-    {
-        test_file(None, f, expected)
+    pub fn test_puzzle<S: Solution>() {
+        test_file::<S>(None)
     }
 }
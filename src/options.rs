@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// The kind of value an option takes.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Kind {
+    /// A flag without value, eg: `--debug`.
+    Flag,
+    /// An integer value, eg: `--row 10` or `--row=10`.
+    Int,
+    /// A textual value, eg: `--ext test2`.
+    Text,
+}
+
+/// Declaration of a command-line option, either of the runner or of a single day.
+#[derive(Clone,Copy,Debug)]
+pub struct Opt {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub help: &'static str,
+}
+
+impl Opt {
+    pub const fn flag(name: &'static str, help: &'static str) -> Self {
+        Opt { name, kind: Kind::Flag, help }
+    }
+
+    pub const fn int(name: &'static str, help: &'static str) -> Self {
+        Opt { name, kind: Kind::Int, help }
+    }
+
+    pub const fn text(name: &'static str, help: &'static str) -> Self {
+        Opt { name, kind: Kind::Text, help }
+    }
+}
+
+#[derive(Clone,Debug,Eq,PartialEq)]
+enum Value {
+    Flag,
+    Int(i64),
+    Text(String),
+}
+
+/// The options given on the command-line, validated against their declarations.
+/// Passed to the runner and to [crate::Solution::parse].
+#[derive(Clone,Debug,Default)]
+pub struct Context {
+    values: HashMap<&'static str, Value>,
+}
+
+impl Context {
+    pub fn flag(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.values.get(name) {
+            Some(Value::Int(value)) => Some(*value),
+            _                       => None,
+        }
+    }
+
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match self.values.get(name) {
+            Some(Value::Text(value)) => Some(value),
+            _                        => None,
+        }
+    }
+
+    /// The names of all options that were given.
+    pub fn names(&self) -> impl Iterator<Item=&'static str> + '_ {
+        self.values.keys().copied()
+    }
+
+    /// Parses the arguments (excluding the executable) against the declared options.
+    /// Returns the context together with the positional arguments.
+    pub fn parse<'a>(args: impl IntoIterator<Item=String>, opts: impl IntoIterator<Item=&'a Opt> + Clone) -> Result<(Self, Vec<String>), OptionError> {
+        let mut context = Context::default();
+        let mut positional = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let name = match arg.as_str() {
+                "-h"                       => "help",
+                _ if arg.starts_with("--") => &arg[2..],
+                _                          => { positional.push(arg); continue },
+            };
+            let (name, inline) = match name.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_owned())),
+                None                => (name, None),
+            };
+
+            let opt = opts
+                .clone()
+                .into_iter()
+                .find(|opt| opt.name == name)
+                .ok_or_else(|| OptionError(format!("unknown option '--{}'", name)))?;
+
+            let value = match opt.kind {
+                Kind::Flag if inline.is_some() => return Err(OptionError(format!("option '--{}' takes no value", name))),
+                Kind::Flag => Value::Flag,
+                Kind::Int | Kind::Text => {
+                    let value = inline
+                        .or_else(|| args.next())
+                        .ok_or_else(|| OptionError(format!("option '--{}' requires a value", name)))?;
+                    if opt.kind == Kind::Int {
+                        let value = value
+                            .parse()
+                            .map_err(|_| OptionError(format!("option '--{}' expects an integer, got {:?}", name, value)))?;
+                        Value::Int(value)
+                    } else {
+                        Value::Text(value)
+                    }
+                },
+            };
+            context.values.insert(opt.name, value);
+        }
+
+        Ok((context, positional))
+    }
+}
+
+/// Formats the options as help-text, one option per line.
+pub fn help<'a>(opts: impl IntoIterator<Item=&'a Opt>) -> String {
+    opts.into_iter()
+        .map(|opt| {
+            let usage = match opt.kind {
+                Kind::Flag => format!("--{}", opt.name),
+                Kind::Int  => format!("--{} <N>", opt.name),
+                Kind::Text => format!("--{} <{}>", opt.name, opt.name.to_uppercase()),
+            };
+            format!("  {:<16} {}\n", usage, opt.help)
+        })
+        .collect()
+}
+
+/// Error for invalid command-line options.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub struct OptionError(pub String);
+
+impl Display for OptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for OptionError {}
+
+#[cfg(test)]
+mod test {
+    use super::{Context, Opt, OptionError};
+
+    const OPTS: &[Opt] = &[
+        Opt::flag("debug", ""),
+        Opt::int("row", ""),
+        Opt::text("ext", ""),
+    ];
+
+    fn parse(args: &[&str]) -> Result<(Context, Vec<String>), OptionError> {
+        Context::parse(args.iter().map(|arg| arg.to_string()), OPTS)
+    }
+
+    #[test]
+    fn values() {
+        let (ctx, positional) = parse(&["15", "--row", "10", "--ext=test2", "--debug"]).unwrap();
+        assert_eq!(positional, vec!["15"]);
+        assert_eq!(ctx.int("row"), Some(10));
+        assert_eq!(ctx.text("ext"), Some("test2"));
+        assert!(ctx.flag("debug"));
+        assert!(!ctx.flag("full"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse(&["--full"]).is_err());
+        assert!(parse(&["--row"]).is_err());
+        assert!(parse(&["--row", "x"]).is_err());
+        assert!(parse(&["--debug=1"]).is_err());
+    }
+}
@@ -3,6 +3,9 @@ use std::ops::IndexMut;
 use std::ops::Index;
 use std::str::FromStr;
 use std::fmt::Display;
+use std::num::ParseIntError;
+
+use crate::ParseError;
 
 #[derive(Eq,PartialEq,Hash,Copy,Clone,Ord,PartialOrd,Debug)]
 pub struct Point<const N: usize>(pub [isize;N]);
@@ -122,10 +125,17 @@ impl<const N: usize> Display for Offset<N> {
 }
 
 impl<const N: usize> FromStr for Point<N> {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Point<N>,()> {
-        let coords = s.split(',').map(|s| s.parse().unwrap()).collect::<Vec<_>>();
-        Ok(Point::new(&coords))
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Point<N>,ParseError> {
+        let coords = s
+            .split(',')
+            .map(str::trim)
+            .map(|c| c.parse().map_err(|err: ParseIntError| ParseError::from(err).within(s, c)))
+            .collect::<Result<Vec<_>,_>>()?;
+        let coords = coords
+            .try_into()
+            .map_err(|coords: Vec<_>| ParseError::new(format!("expected {} coordinates, found {}", N, coords.len())))?;
+        Ok(Point(coords))
     }
 }
 
@@ -0,0 +1,344 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::grid::{BitGrid, Bounds, Grid, GridView, SparseGrid};
+
+/// A grid-like source of cells to render, addressed by signed (row, col).
+pub trait Canvas {
+    type Item;
+
+    /// The cells to render, None if there are none.
+    fn bounds(&self) -> Option<Bounds>;
+    fn item(&self, row: isize, col: isize) -> &Self::Item;
+}
+
+impl<T> Canvas for Grid<T> {
+    type Item = T;
+
+    fn bounds(&self) -> Option<Bounds> {
+        dense_bounds(self.rows, self.cols)
+    }
+
+    fn item(&self, row: isize, col: isize) -> &T {
+        self.get(row as usize, col as usize)
+    }
+}
+
+impl<T> Canvas for GridView<'_, T> {
+    type Item = T;
+
+    fn bounds(&self) -> Option<Bounds> {
+        dense_bounds(self.rows(), self.cols())
+    }
+
+    fn item(&self, row: isize, col: isize) -> &T {
+        self.get(row as usize, col as usize)
+    }
+}
+
+impl<T> Canvas for SparseGrid<T> {
+    type Item = T;
+
+    fn bounds(&self) -> Option<Bounds> {
+        SparseGrid::bounds(self)
+    }
+
+    fn item(&self, row: isize, col: isize) -> &T {
+        self.get(row, col)
+    }
+}
+
+impl Canvas for BitGrid {
+    type Item = bool;
+
+    fn bounds(&self) -> Option<Bounds> {
+        dense_bounds(self.rows, self.cols)
+    }
+
+    fn item(&self, row: isize, col: isize) -> &bool {
+        if self.get(row as usize, col as usize) { &true } else { &false }
+    }
+}
+
+fn dense_bounds(rows: usize, cols: usize) -> Option<Bounds> {
+    if rows == 0 || cols == 0 {
+        return None;
+    }
+    Some(Bounds { min_row: 0, max_row: rows as isize - 1, min_col: 0, max_col: cols as isize - 1 })
+}
+
+/// The ANSI terminal colours.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        match self {
+            Color::Red     => 31,
+            Color::Green   => 32,
+            Color::Yellow  => 33,
+            Color::Blue    => 34,
+            Color::Magenta => 35,
+            Color::Cyan    => 36,
+            Color::White   => 37,
+            Color::Gray    => 90,
+        }
+    }
+}
+
+/// A rendered cell: a character with an optional colour, which is only shown when colours are enabled.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub struct Glyph {
+    pub c: char,
+    pub color: Option<Color>,
+}
+
+impl Glyph {
+    pub fn colored(c: char, color: Color) -> Self {
+        Glyph { c, color: Some(color) }
+    }
+}
+
+impl From<char> for Glyph {
+    fn from(c: char) -> Self {
+        Glyph { c, color: None }
+    }
+}
+
+/// The part of the cells to render.
+#[derive(Clone,Copy,Debug)]
+enum Viewport {
+    All,
+    Fixed(Bounds),
+    /// A window of the given size, centered on the cell as far as the cells allow.
+    Around { row: isize, col: isize, height: usize, width: usize },
+}
+
+/// Renders grid-like sources as text, one line per row, eg: columns 8 to 11 with labels and row 10 highlighted
+/// (without colours):
+///
+/// ```text
+///      1
+///      0
+///  9 ..#.
+/// 10>.###
+/// ```
+///
+/// Options are set builder-style, after which the renderer can be used for any number of renders.
+/// Highlighted rows and cells are shown in bold colour when colours are enabled, without colours
+/// highlighted rows are only marked with '>' after their label.
+#[derive(Clone,Debug)]
+pub struct Renderer {
+    labels: bool,
+    color: bool,
+    viewport: Viewport,
+    rows: HashSet<isize>,
+    cells: HashSet<(isize, isize)>,
+    highlight: Color,
+}
+
+/// Column labels are shown for every n-th column.
+const LABEL_EVERY: isize = 5;
+const RESET: &str = "\x1b[0m";
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Renderer {
+            labels: false,
+            color: false,
+            viewport: Viewport::All,
+            rows: HashSet::new(),
+            cells: HashSet::new(),
+            highlight: Color::Red,
+        }
+    }
+}
+
+impl Renderer {
+    pub fn new() -> Self {
+        Renderer::default()
+    }
+
+    /// Labels the rows on the left and every fifth column on top (written vertically).
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Enables ANSI colours.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Only renders the cells within the bounds.
+    pub fn viewport(mut self, bounds: Bounds) -> Self {
+        self.viewport = Viewport::Fixed(bounds);
+        self
+    }
+
+    /// Only renders a window of the given size, scrolled to keep the cell centered, eg: to follow a moving object.
+    pub fn around(mut self, row: isize, col: isize, height: usize, width: usize) -> Self {
+        self.viewport = Viewport::Around { row, col, height, width };
+        self
+    }
+
+    pub fn highlight_row(mut self, row: isize) -> Self {
+        self.rows.insert(row);
+        self
+    }
+
+    pub fn highlight_cells(mut self, cells: impl IntoIterator<Item=(isize, isize)>) -> Self {
+        self.cells.extend(cells);
+        self
+    }
+
+    /// The colour of highlighted rows and cells, red by default.
+    pub fn highlight_color(mut self, color: Color) -> Self {
+        self.highlight = color;
+        self
+    }
+
+    /// Renders the canvas, mapping each item to its glyph.
+    pub fn grid<C: Canvas>(&self, canvas: &C, glyph: impl Fn(&C::Item) -> Glyph) -> String {
+        match canvas.bounds() {
+            Some(bounds) => self.cells(bounds, |row, col| glyph(canvas.item(row, col))),
+            None         => String::new(),
+        }
+    }
+
+    /// Renders the cells within the bounds, calculating the glyph of each (row, col), eg: for computed images.
+    pub fn cells(&self, bounds: Bounds, glyph: impl Fn(isize, isize) -> Glyph) -> String {
+        let Some(bounds) = self.visible(bounds) else {
+            return String::new();
+        };
+
+        let mut out = String::new();
+        let gutter = if self.labels {
+            let gutter = bounds.min_row.to_string().len().max(bounds.max_row.to_string().len());
+            self.write_col_labels(&mut out, bounds, gutter + 1);
+            gutter
+        } else {
+            0
+        };
+
+        for row in bounds.rows() {
+            let highlight_row = self.rows.contains(&row);
+            if self.labels {
+                let marker = if highlight_row && !self.color { '>' } else { ' ' };
+                write!(out, "{:>gutter$}{}", row, marker).unwrap();
+            }
+
+            let mut style = None;
+            for col in bounds.cols() {
+                let glyph = glyph(row, col);
+                if self.color {
+                    let next = if highlight_row || self.cells.contains(&(row, col)) {
+                        Some((true, self.highlight))
+                    } else {
+                        glyph.color.map(|color| (false, color))
+                    };
+                    if next != style {
+                        match next {
+                            Some((bold, color)) => write!(out, "\x1b[0;{}{}m", if bold { "1;" } else { "" }, color.code()).unwrap(),
+                            None                => out.push_str(RESET),
+                        }
+                        style = next;
+                    }
+                }
+                out.push(glyph.c);
+            }
+            if style.is_some() {
+                out.push_str(RESET);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The part of the bounds within the viewport.
+    fn visible(&self, bounds: Bounds) -> Option<Bounds> {
+        match self.viewport {
+            Viewport::All             => Some(bounds),
+            Viewport::Fixed(viewport) => bounds.intersect(&viewport),
+            Viewport::Around { row, col, height, width } => {
+                // scroll as little as needed: stop at the edges of the bounds
+                let start = |center: isize, size: usize, min: isize, max: isize| {
+                    let size = size as isize;
+                    (center - size / 2).min(max - size + 1).max(min)
+                };
+                let min_row = start(row, height, bounds.min_row, bounds.max_row);
+                let min_col = start(col, width, bounds.min_col, bounds.max_col);
+                let viewport = Bounds {
+                    min_row,
+                    max_row: min_row + height as isize - 1,
+                    min_col,
+                    max_col: min_col + width as isize - 1,
+                };
+                bounds.intersect(&viewport)
+            },
+        }
+    }
+
+    fn write_col_labels(&self, out: &mut String, bounds: Bounds, indent: usize) {
+        let labels: Vec<_> = bounds
+            .cols()
+            .map(|col| if col % LABEL_EVERY == 0 { col.to_string() } else { String::new() })
+            .collect();
+        let height = labels.iter().map(String::len).max().unwrap_or(0);
+
+        // right-aligned, so the last digits are on the last line
+        for i in 0..height {
+            let line: String = labels
+                .iter()
+                .map(|label| (i + label.len()).checked_sub(height).and_then(|i| label[i..].chars().next()).unwrap_or(' '))
+                .collect();
+            writeln!(out, "{:indent$}{}", "", line.trim_end()).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Color, Glyph, Renderer};
+    use crate::grid::{Bounds, Grid, SparseGrid};
+
+    #[test]
+    fn labels() {
+        let grid = Grid::from_data(2, 3, vec!['.', '#', '.', '#', '.', '#']);
+        let text = Renderer::new().labels(true).highlight_row(1).grid(&grid, |&c| c.into());
+        assert_eq!(text, "  0\n0 .#.\n1>#.#\n");
+
+        let mut sparse = SparseGrid::new('.');
+        sparse.set(-10, 8, '#');
+        sparse.set(-9, 10, '#');
+        let text = Renderer::new().labels(true).grid(&sparse, |&c| c.into());
+        assert_eq!(text, "      1\n      0\n-10 #..\n -9 ..#\n");
+    }
+
+    #[test]
+    fn viewport() {
+        let grid = Grid::from_data(4, 4, "abcdefghijklmnop".chars().collect());
+        let render = |renderer: Renderer| renderer.grid(&grid, |&c| c.into());
+        assert_eq!(render(Renderer::new().viewport(Bounds { min_row: 1, max_row: 9, min_col: -1, max_col: 0 })), "e\ni\nm\n");
+        assert_eq!(render(Renderer::new().around(0, 2, 2, 2)), "bc\nfg\n");
+        assert_eq!(render(Renderer::new().around(3, 3, 2, 2)), "kl\nop\n");
+    }
+
+    #[test]
+    fn color() {
+        let grid = Grid::from_data(1, 3, vec![1, 1, 2]);
+        let glyph = |&n: &u32| if n == 1 { Glyph::colored('#', Color::Gray) } else { '.'.into() };
+        let text = Renderer::new().color(true).highlight_cells([(0, 1)]).grid(&grid, glyph);
+        assert_eq!(text, "\x1b[0;90m#\x1b[0;1;31m#\x1b[0m.\n");
+    }
+}